@@ -1,11 +1,14 @@
 // Copyright 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use derivative::Derivative;
-use iota_sdk::{
-    types::block::{address::Bech32Address, output::OutputId},
+use iota_sdk::types::block::{
+    address::Bech32Address,
+    output::{NftId, OutputId},
 };
-use iota_sdk_evm::RequestMetadata;
+use iota_sdk_evm::{NodeError, OffLedgerRequest, RequestMetadata};
 use serde::{Deserialize, Serialize};
 
 /// Each public api method.
@@ -16,22 +19,137 @@ use serde::{Deserialize, Serialize};
 pub enum ApiMethod {
     /// Expected response: [`WaspInfo`](crate::Response::WaspInfo)
     GetInfo,
+    /// Expected response: [`Chains`](crate::Response::Chains)
+    GetChains,
+    /// Expected response: [`ChainInfo`](crate::Response::ChainInfo)
+    GetChainInfo { chain: String },
+    /// Expected response: [`CommitteeInfo`](crate::Response::CommitteeInfo)
+    GetCommitteeInfo { chain: String },
+    /// Expected response: [`Dict`](crate::Response::Dict)
+    #[serde(rename_all = "camelCase")]
+    CallView {
+        chain: String,
+        contract_hname: u32,
+        function_hname: u32,
+        #[serde(default)]
+        args: HashMap<String, Vec<u8>>,
+        block_index: Option<u32>,
+    },
     /// Expected response: [`Assets`](crate::Response::Assets)
     GetBalance {
         chain: String,
         address: Bech32Address,
     },
+    /// Expected response: [`Nonce`](crate::Response::Nonce)
+    #[serde(rename_all = "camelCase")]
+    GetAccountNonce { chain: String, agent_id: String },
+    /// Expected response: [`NftIds`](crate::Response::NftIds)
+    #[serde(rename_all = "camelCase")]
+    GetAccountNfts { chain: String, agent_id: String },
+    /// Expected response: [`FoundrySerialNumbers`](crate::Response::FoundrySerialNumbers)
+    #[serde(rename_all = "camelCase")]
+    GetAccountFoundries { chain: String, agent_id: String },
+    /// Expected response: [`NativeTokens`](crate::Response::NativeTokens)
+    #[serde(rename_all = "camelCase")]
+    GetAccountNativeTokens { chain: String, agent_id: String },
+    /// Expected response: [`NftData`](crate::Response::NftData)
+    #[serde(rename_all = "camelCase")]
+    GetNftData { chain: String, nft_id: NftId },
+    /// Expected response: [`TokenIds`](crate::Response::TokenIds)
+    GetNativeTokenIds { chain: String },
+    /// Expected response: [`L2Assets`](crate::Response::L2Assets)
+    GetTotalAssets { chain: String },
+    /// Expected response: [`AccountsPage`](crate::Response::AccountsPage)
+    #[serde(rename_all = "camelCase")]
+    GetAccounts {
+        chain: String,
+        offset: usize,
+        page_size: usize,
+    },
+    /// Expected response: [`Accounts`](crate::Response::Accounts)
+    GetAllAccounts { chain: String },
+    /// Expected response: [`FoundryOutput`](crate::Response::FoundryOutput)
+    #[serde(rename_all = "camelCase")]
+    GetFoundryOutput { chain: String, serial_number: u32 },
+    /// Expected response: [`BytesArray`](crate::Response::BytesArray)
+    GetStateValue {
+        chain: String,
+        #[serde(with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+        key: Vec<u8>,
+    },
+    /// Expected response: [`BlobFields`](crate::Response::BlobFields)
+    #[serde(rename_all = "camelCase")]
+    GetBlobInfo {
+        chain: String,
+        #[serde(with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+        blob_hash: [u8; 32],
+    },
+    /// Expected response: [`BytesArray`](crate::Response::BytesArray)
+    #[serde(rename_all = "camelCase")]
+    GetBlobField {
+        chain: String,
+        #[serde(with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+        blob_hash: [u8; 32],
+        field: String,
+    },
     /// Expected response: [`Receipt`](crate::Response::Receipt)
     EstimateGasOnLedger {
         chain: String,
         json: serde_json::Value,
     },
     /// Expected response: [`Receipt`](crate::Response::Receipt)
+    EstimateGasForRequest {
+        chain: String,
+        metadata: RequestMetadata,
+        sender: Bech32Address,
+        #[serde(with = "iota_sdk::utils::serde::string")]
+        amount: u64,
+    },
+    /// Expected response: [`Receipt`](crate::Response::Receipt)
     EstimateGasOffLedger {
         chain: String,
         metadata: RequestMetadata,
     },
+    /// Expected response: [`RequestId`](crate::Response::RequestId)
+    SubmitOffLedger {
+        chain: String,
+        request: OffLedgerRequest,
+    },
     /// Expected response: [`Receipt`](crate::Response::Receipt)
     #[serde(rename_all = "camelCase")]
     GetReceipt { chain: String, request_id: OutputId },
+    /// Expected response: [`VmError`](crate::Response::VmError)
+    ResolveVmError { chain: String, error: NodeError },
+    /// Expected response: [`Receipt`](crate::Response::Receipt)
+    #[serde(rename_all = "camelCase")]
+    WaitForRequest {
+        chain: String,
+        request_id: OutputId,
+        timeout_secs: u64,
+    },
+    /// Expected response: [`BlockInfo`](crate::Response::BlockInfo)
+    GetLatestBlockInfo { chain: String },
+    /// Expected response: [`BlockInfo`](crate::Response::BlockInfo)
+    #[serde(rename_all = "camelCase")]
+    GetBlockInfo { chain: String, block_index: u32 },
+    /// Expected response: [`RequestIds`](crate::Response::RequestIds)
+    #[serde(rename_all = "camelCase")]
+    GetBlockRequestIds { chain: String, block_index: u32 },
+    /// Expected response: [`Receipts`](crate::Response::Receipts)
+    #[serde(rename_all = "camelCase")]
+    GetBlockReceipts { chain: String, block_index: u32 },
+    /// Expected response: [`Bool`](crate::Response::Bool)
+    #[serde(rename_all = "camelCase")]
+    IsRequestProcessed { chain: String, request_id: OutputId },
+    /// Expected response: [`Events`](crate::Response::Events)
+    #[serde(rename_all = "camelCase")]
+    GetEventsForBlock { chain: String, block_index: u32 },
+    /// Expected response: [`Events`](crate::Response::Events)
+    #[serde(rename_all = "camelCase")]
+    GetEventsForRequest { chain: String, request_id: OutputId },
+    /// Expected response: [`Events`](crate::Response::Events)
+    #[serde(rename_all = "camelCase")]
+    GetEventsForContract { chain: String, contract_hname: u32 },
+    /// Expected response: [`CacheStats`](crate::Response::CacheStats)
+    GetCacheStats,
 }
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use derivative::Derivative;
-use iota_sdk_evm::{ContractIdentity, RequestMetadata};
+use iota_sdk_evm::{Blob, ContractIdentity, OffLedgerRequest, RequestMetadata};
 use serde::{Deserialize, Serialize};
 
 /// Each public utils method.
@@ -21,4 +21,12 @@ pub enum UtilsMethod {
     SpecialEncode {
         metadata: RequestMetadata,
     },
+    /// Returns the hex encoded bytes an off-ledger request has to be signed over.
+    OffLedgerRequestEssence {
+        request: OffLedgerRequest,
+    },
+    /// Returns the hash the chain identifies a blob with.
+    BlobHash {
+        blob: Blob,
+    },
 }
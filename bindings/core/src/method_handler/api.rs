@@ -1,6 +1,8 @@
 // Copyright 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
 use iota_sdk_evm::Api;
 
 use crate::{method::ApiMethod, response::Response, Result};
@@ -9,18 +11,128 @@ use crate::{method::ApiMethod, response::Response, Result};
 pub(crate) async fn call_api_method_internal(api: &Api, method: ApiMethod) -> Result<Response> {
     let response = match method {
         ApiMethod::GetInfo => Response::WaspInfo(api.info().await?),
+        ApiMethod::GetChains => Response::Chains(api.chains().await?),
+        ApiMethod::GetChainInfo { chain } => Response::ChainInfo(api.chain_info(&chain).await?),
+        ApiMethod::GetCommitteeInfo { chain } => {
+            Response::CommitteeInfo(api.committee_info(&chain).await?)
+        }
+        ApiMethod::CallView {
+            chain,
+            contract_hname,
+            function_hname,
+            args,
+            block_index,
+        } => Response::Dict(
+            api.call_view(&chain, contract_hname, function_hname, &args, block_index)
+                .await?
+                .into_iter()
+                .map(|(key, value)| (prefix_hex::encode(key), value))
+                .collect(),
+        ),
         ApiMethod::GetBalance { chain, address } => {
             Response::Assets(api.get_balance(&chain, address).await?)
         }
+        ApiMethod::GetAccountNonce { chain, agent_id } => {
+            Response::Nonce(api.get_account_nonce(&chain, agent_id.as_str()).await?)
+        }
+        ApiMethod::GetAccountNfts { chain, agent_id } => {
+            Response::NftIds(api.get_account_nfts(&chain, agent_id.as_str()).await?)
+        }
+        ApiMethod::GetAccountFoundries { chain, agent_id } => Response::FoundrySerialNumbers(
+            api.get_account_foundries(&chain, agent_id.as_str()).await?,
+        ),
+        ApiMethod::GetAccountNativeTokens { chain, agent_id } => Response::NativeTokens(
+            api.get_account_native_tokens(&chain, agent_id.as_str())
+                .await?,
+        ),
+        ApiMethod::GetNftData { chain, nft_id } => {
+            Response::NftData(api.get_nft_data(&chain, nft_id).await?)
+        }
+        ApiMethod::GetNativeTokenIds { chain } => {
+            Response::TokenIds(api.native_token_ids(&chain).await?)
+        }
+        ApiMethod::GetTotalAssets { chain } => Response::L2Assets(api.total_assets(&chain).await?),
+        ApiMethod::GetAccounts {
+            chain,
+            offset,
+            page_size,
+        } => Response::AccountsPage(api.accounts(&chain, offset, page_size).await?),
+        ApiMethod::GetAllAccounts { chain } => Response::Accounts(api.all_accounts(&chain).await?),
+        ApiMethod::GetFoundryOutput {
+            chain,
+            serial_number,
+        } => {
+            Response::FoundryOutput((&api.get_foundry_output(&chain, serial_number).await?).into())
+        }
+        ApiMethod::GetStateValue { chain, key } => {
+            Response::BytesArray(api.get_state_value(&chain, &key).await?)
+        }
+        ApiMethod::GetBlobInfo { chain, blob_hash } => {
+            Response::BlobFields(api.blob_info(&chain, &blob_hash).await?)
+        }
+        ApiMethod::GetBlobField {
+            chain,
+            blob_hash,
+            field,
+        } => Response::BytesArray(api.blob_field(&chain, &blob_hash, &field).await?),
         ApiMethod::EstimateGasOnLedger { chain, json } => {
             Response::Receipt(api.estimate_gas_on_ledger(&chain, json).await?)
         }
+        ApiMethod::EstimateGasForRequest {
+            chain,
+            metadata,
+            sender,
+            amount,
+        } => Response::Receipt(
+            api.estimate_gas_for_request(&chain, &metadata, sender.inner(), amount)
+                .await?,
+        ),
         ApiMethod::EstimateGasOffLedger { chain, metadata } => {
             Response::Receipt(api.estimate_gas_off_ledger(&chain, &metadata).await?)
         }
+        ApiMethod::SubmitOffLedger { chain, request } => {
+            Response::RequestId(api.submit_off_ledger(&chain, &request).await?)
+        }
         ApiMethod::GetReceipt { chain, request_id } => {
             Response::Receipt(api.get_receipt(&chain, request_id).await?)
         }
+        ApiMethod::ResolveVmError { chain, error } => {
+            Response::VmError(api.resolve_vm_error(&chain, &error).await?)
+        }
+        ApiMethod::WaitForRequest {
+            chain,
+            request_id,
+            timeout_secs,
+        } => Response::Receipt(
+            api.wait_for_request(&chain, request_id, Duration::from_secs(timeout_secs))
+                .await?,
+        ),
+        ApiMethod::GetLatestBlockInfo { chain } => {
+            Response::BlockInfo(api.latest_block_info(&chain).await?)
+        }
+        ApiMethod::GetBlockInfo { chain, block_index } => {
+            Response::BlockInfo(api.block_info(&chain, block_index).await?)
+        }
+        ApiMethod::GetBlockRequestIds { chain, block_index } => {
+            Response::RequestIds(api.block_request_ids(&chain, block_index).await?)
+        }
+        ApiMethod::GetBlockReceipts { chain, block_index } => {
+            Response::Receipts(api.block_receipts(&chain, block_index).await?)
+        }
+        ApiMethod::IsRequestProcessed { chain, request_id } => {
+            Response::Bool(api.is_request_processed(&chain, request_id).await?)
+        }
+        ApiMethod::GetEventsForBlock { chain, block_index } => {
+            Response::Events(api.events_for_block(&chain, block_index).await?)
+        }
+        ApiMethod::GetEventsForRequest { chain, request_id } => {
+            Response::Events(api.events_for_request(&chain, request_id).await?)
+        }
+        ApiMethod::GetEventsForContract {
+            chain,
+            contract_hname,
+        } => Response::Events(api.events_for_contract(&chain, contract_hname).await?),
+        ApiMethod::GetCacheStats => Response::CacheStats(api.cache_stats()),
     };
 
     Ok(response)
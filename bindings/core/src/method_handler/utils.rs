@@ -18,6 +18,10 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
         UtilsMethod::SpecialEncode { metadata } => {
             Response::SpecialEncoded(hex::encode(metadata.pack_to_vec()))
         }
+        UtilsMethod::OffLedgerRequestEssence { request } => {
+            Response::SpecialEncoded(hex::encode(request.essence_bytes()))
+        }
+        UtilsMethod::BlobHash { blob } => Response::Hash(prefix_hex::encode(blob.hash())),
     };
 
     Ok(response)
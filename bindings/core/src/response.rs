@@ -1,8 +1,16 @@
 // Copyright 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use derivative::Derivative;
-use iota_sdk_evm::{AssetsDto, ReceiptResponse, WaspInfo};
+use iota_sdk::types::block::output::{
+    dto::FoundryOutputDto, NativeToken, NftId, OutputId, TokenId,
+};
+use iota_sdk_evm::{
+    AccountsPage, AgentIdentity, Assets, AssetsDto, BlockInfo, CacheStats, ChainInfo, ChainSummary,
+    CommitteeInfo, Event, NftData, ReceiptResponse, VmError, WaspInfo,
+};
 use serde::Serialize;
 
 use crate::Error;
@@ -17,22 +25,105 @@ pub enum Response {
     /// - [`GetInfo`](crate::method::Api::GetInfo)
     WaspInfo(WaspInfo),
     /// Response for:
+    /// - [`GetChains`](crate::method::Api::GetChains)
+    Chains(Vec<ChainSummary>),
+    /// Response for:
+    /// - [`GetChainInfo`](crate::method::Api::GetChainInfo)
+    ChainInfo(ChainInfo),
+    /// Response for:
+    /// - [`GetCommitteeInfo`](crate::method::Api::GetCommitteeInfo)
+    CommitteeInfo(CommitteeInfo),
+    /// Response for:
+    /// - [`CallView`](crate::method::Api::CallView)
+    ///
+    /// The keys are hex encoded, they often aren't valid UTF-8.
+    Dict(HashMap<String, Vec<u8>>),
+    /// Response for:
     /// - [`Balance`](crate::method::Api::Balance)
     Assets(AssetsDto),
     /// Response for:
+    /// - [`GetTotalAssets`](crate::method::Api::GetTotalAssets)
+    L2Assets(Assets),
+    /// Response for:
+    /// - [`GetAccounts`](crate::method::Api::GetAccounts)
+    AccountsPage(AccountsPage),
+    /// Response for:
+    /// - [`GetAllAccounts`](crate::method::Api::GetAllAccounts)
+    Accounts(Vec<AgentIdentity>),
+    /// Response for:
+    /// - [`GetAccountNonce`](crate::method::Api::GetAccountNonce)
+    #[serde(with = "iota_sdk::utils::serde::string")]
+    Nonce(u64),
+    /// Response for:
+    /// - [`GetAccountNfts`](crate::method::Api::GetAccountNfts)
+    NftIds(Vec<NftId>),
+    /// Response for:
+    /// - [`GetAccountFoundries`](crate::method::Api::GetAccountFoundries)
+    FoundrySerialNumbers(Vec<u32>),
+    /// Response for:
+    /// - [`GetNftData`](crate::method::Api::GetNftData)
+    NftData(NftData),
+    /// Response for:
+    /// - [`GetNativeTokenIds`](crate::method::Api::GetNativeTokenIds)
+    TokenIds(Vec<TokenId>),
+    /// Response for:
+    /// - [`GetAccountNativeTokens`](crate::method::Api::GetAccountNativeTokens)
+    NativeTokens(Vec<NativeToken>),
+    /// Response for:
+    /// - [`GetFoundryOutput`](crate::method::Api::GetFoundryOutput)
+    FoundryOutput(FoundryOutputDto),
+    /// Response for:
     /// - [`Hname`](crate::method::Utils::Hname)
     Number(u32),
     /// Response for:
     /// - [`SpecialEncode`](crate::method::Utils::SpecialEncode)
+    /// - [`OffLedgerRequestEssence`](crate::method::Utils::OffLedgerRequestEssence)
     SpecialEncoded(String),
     /// Response for:
     /// - [`EthereumAgentId`](crate::method::Utils::EthereumAgentId)
+    /// - [`GetStateValue`](crate::method::Api::GetStateValue)
+    /// - [`GetBlobField`](crate::method::Api::GetBlobField)
     BytesArray(Vec<u8>),
     /// Response for:
+    /// - [`BlobHash`](crate::method::Utils::BlobHash)
+    Hash(String),
+    /// Response for:
+    /// - [`GetBlobInfo`](crate::method::Api::GetBlobInfo)
+    BlobFields(HashMap<String, u32>),
+    /// Response for:
     /// - [`EstimateGasOnLedger`](crate::method::Api::EstimateGasOnLedger)
+    /// - [`EstimateGasForRequest`](crate::method::Api::EstimateGasForRequest)
     /// - [`EstimateGasOffLedger`](crate::method::Api::EstimateGasOffLedger)
     /// - [`GetReceipt`](crate::method::Api::GetReceipt)
+    /// - [`WaitForRequest`](crate::method::Api::WaitForRequest)
     Receipt(ReceiptResponse),
+    /// Response for:
+    /// - [`ResolveVmError`](crate::method::Api::ResolveVmError)
+    VmError(Option<VmError>),
+    /// Response for:
+    /// - [`SubmitOffLedger`](crate::method::Api::SubmitOffLedger)
+    RequestId(OutputId),
+    /// Response for:
+    /// - [`GetLatestBlockInfo`](crate::method::Api::GetLatestBlockInfo)
+    /// - [`GetBlockInfo`](crate::method::Api::GetBlockInfo)
+    BlockInfo(BlockInfo),
+    /// Response for:
+    /// - [`GetBlockRequestIds`](crate::method::Api::GetBlockRequestIds)
+    RequestIds(Vec<OutputId>),
+    /// Response for:
+    /// - [`GetBlockReceipts`](crate::method::Api::GetBlockReceipts)
+    Receipts(Vec<ReceiptResponse>),
+    /// Response for:
+    /// - [`IsRequestProcessed`](crate::method::Api::IsRequestProcessed)
+    Bool(bool),
+    /// Response for:
+    /// - [`GetEventsForBlock`](crate::method::Api::GetEventsForBlock)
+    /// - [`GetEventsForRequest`](crate::method::Api::GetEventsForRequest)
+    /// - [`GetEventsForContract`](crate::method::Api::GetEventsForContract)
+    Events(Vec<Event>),
+    /// Response for:
+    /// - [`GetCacheStats`](crate::method::Api::GetCacheStats)
+    CacheStats(Option<CacheStats>),
     Ok,
     /// Response for any method that returns an error.
     Error(Error),
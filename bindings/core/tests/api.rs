@@ -1,2 +1,38 @@
 // Copyright 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk_evm::{Api, HttpRequest, HttpResponse, Transport, TransportFuture};
+use iota_sdk_evm_bindings_core::{call_api_method, ApiMethod, Response};
+use url::Url;
+
+/// Answers every request like a node hosting one chain.
+struct FakeNode;
+
+impl Transport for FakeNode {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        let body = match request.url.path() {
+            "/v1/chains" => br#"[{"chainID":"chain","isActive":true}]"#.to_vec(),
+            _ => b"{}".to_vec(),
+        };
+        Box::pin(async move {
+            Ok(HttpResponse {
+                status: 200,
+                body,
+                ..Default::default()
+            })
+        })
+    }
+}
+
+#[tokio::test]
+async fn call_api_method_without_network() {
+    let api = Api::new(Url::parse("http://localhost:9090").unwrap()).with_transport(FakeNode);
+
+    match call_api_method(&api, ApiMethod::GetChains).await {
+        Response::Chains(chains) => {
+            assert_eq!(chains.len(), 1);
+            assert_eq!(chains[0].chain_id, "chain");
+        }
+        response => panic!("unexpected response: {response:?}"),
+    }
+}
@@ -4,6 +4,7 @@
 import { errorHandle } from '..';
 import { callApiMethod, createApi, destroyApi } from '../bindings';
 import type { __ApiMethods__ } from '../types/api';
+import type { ApiOptions } from '../types/api-options';
 
 /**
  * The MethodHandler which sends the commands to the Rust side.
@@ -19,11 +20,14 @@ export class ApiMethodHandler {
     }
 
     /**
-     * @param options The client options.
+     * @param options The api options, or the url of a single node.
      */
-    static async create(url: String): Promise<ApiMethodHandler> {
+    static async create(options: ApiOptions | string): Promise<ApiMethodHandler> {
+        if (typeof options === 'string') {
+            options = { nodes: [options] };
+        }
         try {
-            const methodHandler = await createApi(url);
+            const methodHandler = await createApi(JSON.stringify(options));
             return new ApiMethodHandler(methodHandler);
         } catch (error: any) {
             throw errorHandle(error);
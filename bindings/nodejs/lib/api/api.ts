@@ -1,10 +1,35 @@
 // Copyright 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-import { Assets, ReceiptResponse, RequestMetadata, WaspInfo } from '../types';
+import {
+    AccountsPage,
+    ApiOptions,
+    Assets,
+    BlockInfo,
+    CacheStats,
+    ChainInfo,
+    ChainSummary,
+    CommitteeInfo,
+    Event,
+    NftData,
+    NodeError,
+    OffLedgerRequest,
+    ReceiptResponse,
+    RequestMetadata,
+    VmError,
+    WaspInfo,
+} from '../types';
 import { ApiMethodHandler } from './api-method-handler';
 
-import { Bech32Address, OutputId } from '@iota/sdk';
+import {
+    Bech32Address,
+    FoundryOutput,
+    HexEncodedString,
+    INativeToken,
+    NftId,
+    OutputId,
+    TokenId,
+} from '@iota/sdk';
 
 /** The Client to interact with nodes. */
 export class Api {
@@ -18,10 +43,10 @@ export class Api {
     }
 
     /**
-     * @param options The client options.
+     * @param options The api options, or the url of a single node.
      */
-    static async create(url: String): Promise<Api> {
-        return new Api(await ApiMethodHandler.create(url));
+    static async create(options: ApiOptions | string): Promise<Api> {
+        return new Api(await ApiMethodHandler.create(options));
     }
     async destroy(): Promise<void> {
         return this.methodHandler.destroy();
@@ -38,6 +63,72 @@ export class Api {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the chains hosted by the node and whether they are active.
+     */
+    async getChains(): Promise<ChainSummary[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getChains',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the information of a chain, including its fee policy and gas limits.
+     */
+    async getChainInfo(chain: string): Promise<ChainInfo> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getChainInfo',
+            data: {
+                chain,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the committee and access nodes of a chain and whether they are alive and trusted.
+     */
+    async getCommitteeInfo(chain: string): Promise<CommitteeInfo> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getCommitteeInfo',
+            data: {
+                chain,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Call a view function of a contract.
+     *
+     * @param blockIndex Query the state at this block instead of the latest one.
+     * @returns The resulting dict, with hex encoded keys as they often aren't valid UTF-8.
+     */
+    async callView(
+        chain: string,
+        contractHname: number,
+        functionHname: number,
+        args: Record<string, number[]> = {},
+        blockIndex?: number,
+    ): Promise<Record<HexEncodedString, number[]>> {
+        const response = await this.methodHandler.callMethod({
+            name: 'callView',
+            data: {
+                chain,
+                contractHname,
+                functionHname,
+                args,
+                blockIndex,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Returns the balance of an l1 address available for l2 transfers.
      */
@@ -53,6 +144,230 @@ export class Api {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Returns the nonce of an L2 account.
+     *
+     * @param agentId A bech32 address, a 0x prefixed EVM address or any other agent ID string.
+     */
+    async getAccountNonce(chain: string, agentId: string): Promise<bigint> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getAccountNonce',
+            data: {
+                chain,
+                agentId,
+            },
+        });
+
+        return BigInt(JSON.parse(response).payload);
+    }
+
+    /**
+     * Returns the IDs of the NFTs owned by an L2 account.
+     */
+    async getAccountNfts(chain: string, agentId: string): Promise<NftId[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getAccountNfts',
+            data: {
+                chain,
+                agentId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns the serial numbers of the foundries owned by an L2 account.
+     */
+    async getAccountFoundries(
+        chain: string,
+        agentId: string,
+    ): Promise<number[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getAccountFoundries',
+            data: {
+                chain,
+                agentId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns the native tokens held by an L2 account.
+     */
+    async getAccountNativeTokens(
+        chain: string,
+        agentId: string,
+    ): Promise<INativeToken[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getAccountNativeTokens',
+            data: {
+                chain,
+                agentId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns the data of an NFT held on the chain.
+     */
+    async getNftData(chain: string, nftId: NftId): Promise<NftData> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getNftData',
+            data: {
+                chain,
+                nftId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns the IDs of all native tokens registered on the chain.
+     */
+    async getNativeTokenIds(chain: string): Promise<TokenId[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getNativeTokenIds',
+            data: {
+                chain,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns the sum of the fungible tokens held by all L2 accounts of the chain.
+     */
+    async getTotalAssets(chain: string): Promise<Assets> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getTotalAssets',
+            data: {
+                chain,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns up to `pageSize` agent IDs of the L2 accounts of the chain, sorted and starting at `offset`.
+     * The node has no paging for accounts, every page downloads the whole list again. Use
+     * `getAllAccounts` to get every account with one request.
+     */
+    async getAccounts(
+        chain: string,
+        offset: number,
+        pageSize: number,
+    ): Promise<AccountsPage> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getAccounts',
+            data: {
+                chain,
+                offset,
+                pageSize,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns the agent IDs of all L2 accounts of the chain, sorted like the pages of `getAccounts`.
+     */
+    async getAllAccounts(chain: string): Promise<string[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getAllAccounts',
+            data: {
+                chain,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns the foundry output with the given serial number.
+     */
+    async getFoundryOutput(
+        chain: string,
+        serialNumber: number,
+    ): Promise<FoundryOutput> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getFoundryOutput',
+            data: {
+                chain,
+                serialNumber,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get a raw value of the chain state.
+     *
+     * @param key The hex encoded state key: the hname of the contract in little endian followed by the key of the field.
+     */
+    async getStateValue(chain: string, key: string): Promise<number[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getStateValue',
+            data: {
+                chain,
+                key,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the fields of a blob and their sizes in bytes.
+     *
+     * @param blobHash The hex encoded hash of the blob.
+     */
+    async getBlobInfo(
+        chain: string,
+        blobHash: string,
+    ): Promise<Record<string, number>> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getBlobInfo',
+            data: {
+                chain,
+                blobHash,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the value of a field of a blob.
+     *
+     * @param blobHash The hex encoded hash of the blob.
+     */
+    async getBlobField(
+        chain: string,
+        blobHash: string,
+        field: string,
+    ): Promise<number[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getBlobField',
+            data: {
+                chain,
+                blobHash,
+                field,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     async estimateGasOnLedger(
         chain: string,
         json: object,
@@ -68,6 +383,28 @@ export class Api {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Estimate the gas of an on-ledger request, sent by `sender` with `amount` base tokens.
+     */
+    async estimateGasForRequest(
+        chain: string,
+        metadata: RequestMetadata,
+        sender: Bech32Address,
+        amount: bigint,
+    ): Promise<ReceiptResponse> {
+        const response = await this.methodHandler.callMethod({
+            name: 'estimateGasForRequest',
+            data: {
+                chain,
+                metadata,
+                sender,
+                amount: amount.toString(),
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     async estimateGasOffLedger(
         chain: string,
         metadata: RequestMetadata,
@@ -83,6 +420,26 @@ export class Api {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Submit a signed off-ledger request to the chain it was signed for.
+     *
+     * @returns The request ID.
+     */
+    async submitOffLedger(
+        chain: string,
+        request: OffLedgerRequest,
+    ): Promise<OutputId> {
+        const response = await this.methodHandler.callMethod({
+            name: 'submitOffLedger',
+            data: {
+                chain,
+                request,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     async getReceipt(
         chain: string,
         requestId: OutputId,
@@ -97,4 +454,195 @@ export class Api {
 
         return JSON.parse(response).payload;
     }
+
+    /**
+     * Resolve the message of a VM error, for example the `rawError` of a receipt.
+     *
+     * @returns The error, or `null` if there is no error.
+     */
+    async resolveVmError(
+        chain: string,
+        error: NodeError,
+    ): Promise<VmError | null> {
+        const response = await this.methodHandler.callMethod({
+            name: 'resolveVmError',
+            data: {
+                chain,
+                error,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Wait until a request has been processed.
+     *
+     * @param timeoutSecs How long to wait before failing with a timeout error.
+     * @returns The receipt of the request.
+     */
+    async waitForRequest(
+        chain: string,
+        requestId: OutputId,
+        timeoutSecs: number = 30,
+    ): Promise<ReceiptResponse> {
+        const response = await this.methodHandler.callMethod({
+            name: 'waitForRequest',
+            data: {
+                chain,
+                requestId,
+                timeoutSecs,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the latest block of a chain.
+     */
+    async getLatestBlockInfo(chain: string): Promise<BlockInfo> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getLatestBlockInfo',
+            data: {
+                chain,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the block with the given index.
+     */
+    async getBlockInfo(chain: string, blockIndex: number): Promise<BlockInfo> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getBlockInfo',
+            data: {
+                chain,
+                blockIndex,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the IDs of the requests processed in a block.
+     */
+    async getBlockRequestIds(
+        chain: string,
+        blockIndex: number,
+    ): Promise<OutputId[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getBlockRequestIds',
+            data: {
+                chain,
+                blockIndex,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the receipts of the requests processed in a block.
+     */
+    async getBlockReceipts(
+        chain: string,
+        blockIndex: number,
+    ): Promise<ReceiptResponse[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getBlockReceipts',
+            data: {
+                chain,
+                blockIndex,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Check whether a request has been processed by the chain.
+     */
+    async isRequestProcessed(
+        chain: string,
+        requestId: OutputId,
+    ): Promise<boolean> {
+        const response = await this.methodHandler.callMethod({
+            name: 'isRequestProcessed',
+            data: {
+                chain,
+                requestId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the events emitted in a block.
+     */
+    async getEventsForBlock(
+        chain: string,
+        blockIndex: number,
+    ): Promise<Event[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getEventsForBlock',
+            data: {
+                chain,
+                blockIndex,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the events emitted while processing a request.
+     */
+    async getEventsForRequest(
+        chain: string,
+        requestId: OutputId,
+    ): Promise<Event[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getEventsForRequest',
+            data: {
+                chain,
+                requestId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the events emitted by a contract.
+     */
+    async getEventsForContract(
+        chain: string,
+        contractHname: number,
+    ): Promise<Event[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getEventsForContract',
+            data: {
+                chain,
+                contractHname,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns how often the response cache could answer a request, or `undefined` if the api has no cache.
+     */
+    async getCacheStats(): Promise<CacheStats | undefined> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getCacheStats',
+        });
+
+        return JSON.parse(response).payload ?? undefined;
+    }
 }
@@ -0,0 +1,77 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/** Basic auth or a JWT for a node. */
+export interface INodeAuth {
+    /** JSON Web Token. */
+    jwt?: string;
+    /** Basic authentication username and password. */
+    basicAuthNamePwd?: [string, string];
+}
+
+/** A node with authentication. */
+export interface INode {
+    /** The url of the node. */
+    url: string;
+    /** The authentication for the node. */
+    auth?: INodeAuth;
+    /** Whether the node is disabled. */
+    disabled?: boolean;
+}
+
+/** Time duration. */
+export interface IDuration {
+    /** Seconds. */
+    secs: number;
+    /** Nanoseconds. */
+    nanos: number;
+}
+
+/** How requests that failed for a temporary reason are retried. */
+export interface IRetryPolicy {
+    /** How often a request is sent at most, including the first attempt. 1 disables retries. */
+    maxAttempts?: number;
+    /** The delay before the first retry, it doubles with every further one. */
+    initialDelay?: IDuration;
+    /** The longest delay between two attempts. */
+    maxDelay?: IDuration;
+    /** Whether requests that change state, like off-ledger requests, are retried too. */
+    retryNonIdempotent?: boolean;
+}
+
+/** Options to create an Api with. */
+export interface ApiOptions {
+    /** The nodes to send requests to, the first one is the primary node. */
+    nodes: Array<string | INode>;
+    /** Whether responses of multiple nodes are compared for requests that need quorum. */
+    quorum?: boolean;
+    /** Minimum amount of nodes required for a request with quorum. */
+    minQuorumSize?: number;
+    /** % of nodes that have to return the same response so it gets accepted. */
+    quorumThreshold?: number;
+    /** Timeout for API requests. */
+    apiTimeout?: IDuration;
+    /** The User-Agent header for requests. */
+    userAgent?: string;
+    /** Headers sent with every request. */
+    headers?: { [name: string]: string };
+    /** PEM encoded root certificate to trust, for nodes with a self-signed certificate. */
+    rootCertificate?: string;
+    /** Accepts any certificate. Only use this for local development. */
+    acceptInvalidCertificates?: boolean;
+    /** Proxy all requests are sent through. */
+    proxy?: string;
+    /** How requests that failed for a temporary reason are retried. */
+    retryPolicy?: IRetryPolicy;
+    /**
+     * How many responses that never change, like receipts of processed requests, are cached.
+     * No cache is used if not set.
+     */
+    cacheCapacity?: number;
+}
+
+/** How often the response cache could answer a request. */
+export interface CacheStats {
+    hits: number;
+    misses: number;
+}
@@ -0,0 +1,19 @@
+import { HexEncodedString, NftId } from '@iota/sdk';
+
+export interface NftData {
+    id: NftId;
+    issuer: string;
+    metadata: HexEncodedString;
+    /** The agent ID of the L2 account owning the NFT. */
+    owner: string;
+}
+
+/** A page of the L2 accounts of a chain, cut from all accounts on the client. */
+export interface AccountsPage {
+    /** The agent IDs of the owners of the accounts. */
+    accounts: string[];
+    /** The offset of the next page, not set if this is the last one. */
+    nextOffset?: number;
+    /** How many accounts the chain has. */
+    total: number;
+}
@@ -1,10 +1,41 @@
-import { OutputId } from '@iota/sdk';
+import { NftId, OutputId } from '@iota/sdk';
+import { NodeError } from '../info';
 import { RequestMetadata } from '../../utils/request-metadata';
+import { OffLedgerRequest } from '../../utils/off-ledger-request';
 
 export interface __GetInfoMethod__ {
     name: 'getInfo';
 }
 
+export interface __GetChainsMethod__ {
+    name: 'getChains';
+}
+
+export interface __GetChainInfoMethod__ {
+    name: 'getChainInfo';
+    data: {
+        chain: string;
+    };
+}
+
+export interface __GetCommitteeInfoMethod__ {
+    name: 'getCommitteeInfo';
+    data: {
+        chain: string;
+    };
+}
+
+export interface __CallViewMethod__ {
+    name: 'callView';
+    data: {
+        chain: string;
+        contractHname: number;
+        functionHname: number;
+        args: Record<string, number[]>;
+        blockIndex?: number;
+    };
+}
+
 export interface __GetBalanceMethod__ {
     name: 'getBalance';
     data: {
@@ -13,6 +44,92 @@ export interface __GetBalanceMethod__ {
     };
 }
 
+export interface __SubmitOffLedgerMethod__ {
+    name: 'submitOffLedger';
+    data: {
+        chain: string;
+        request: OffLedgerRequest;
+    };
+}
+
+export interface __GetAccountNonceMethod__ {
+    name: 'getAccountNonce';
+    data: {
+        chain: string;
+        agentId: string;
+    };
+}
+
+export interface __GetAccountNftsMethod__ {
+    name: 'getAccountNfts';
+    data: {
+        chain: string;
+        agentId: string;
+    };
+}
+
+export interface __GetAccountFoundriesMethod__ {
+    name: 'getAccountFoundries';
+    data: {
+        chain: string;
+        agentId: string;
+    };
+}
+
+export interface __GetAccountNativeTokensMethod__ {
+    name: 'getAccountNativeTokens';
+    data: {
+        chain: string;
+        agentId: string;
+    };
+}
+
+export interface __GetNftDataMethod__ {
+    name: 'getNftData';
+    data: {
+        chain: string;
+        nftId: NftId;
+    };
+}
+
+export interface __GetNativeTokenIdsMethod__ {
+    name: 'getNativeTokenIds';
+    data: {
+        chain: string;
+    };
+}
+
+export interface __GetTotalAssetsMethod__ {
+    name: 'getTotalAssets';
+    data: {
+        chain: string;
+    };
+}
+
+export interface __GetAccountsMethod__ {
+    name: 'getAccounts';
+    data: {
+        chain: string;
+        offset: number;
+        pageSize: number;
+    };
+}
+
+export interface __GetAllAccountsMethod__ {
+    name: 'getAllAccounts';
+    data: {
+        chain: string;
+    };
+}
+
+export interface __GetFoundryOutputMethod__ {
+    name: 'getFoundryOutput';
+    data: {
+        chain: string;
+        serialNumber: number;
+    };
+}
+
 export interface __GetReceiptMethod__ {
     name: 'getReceipt';
     data: {
@@ -21,6 +138,48 @@ export interface __GetReceiptMethod__ {
     };
 }
 
+export interface __ResolveVmErrorMethod__ {
+    name: 'resolveVmError';
+    data: {
+        chain: string;
+        error: NodeError;
+    };
+}
+
+export interface __WaitForRequestMethod__ {
+    name: 'waitForRequest';
+    data: {
+        chain: string;
+        requestId: OutputId;
+        timeoutSecs: number;
+    };
+}
+
+export interface __GetStateValueMethod__ {
+    name: 'getStateValue';
+    data: {
+        chain: string;
+        key: string;
+    };
+}
+
+export interface __GetBlobInfoMethod__ {
+    name: 'getBlobInfo';
+    data: {
+        chain: string;
+        blobHash: string;
+    };
+}
+
+export interface __GetBlobFieldMethod__ {
+    name: 'getBlobField';
+    data: {
+        chain: string;
+        blobHash: string;
+        field: string;
+    };
+}
+
 export interface __PostEstimateGasOnLedgerMethod__ {
     name: 'estimateGasOnLedger';
     data: {
@@ -29,6 +188,16 @@ export interface __PostEstimateGasOnLedgerMethod__ {
     };
 }
 
+export interface __PostEstimateGasForRequestMethod__ {
+    name: 'estimateGasForRequest';
+    data: {
+        chain: string;
+        metadata: RequestMetadata;
+        sender: string;
+        amount: string;
+    };
+}
+
 export interface __PostEstimateGasOffLedgerMethod__ {
     name: 'estimateGasOffLedger';
     data: {
@@ -36,3 +205,70 @@ export interface __PostEstimateGasOffLedgerMethod__ {
         metadata: RequestMetadata;
     };
 }
+
+export interface __GetLatestBlockInfoMethod__ {
+    name: 'getLatestBlockInfo';
+    data: {
+        chain: string;
+    };
+}
+
+export interface __GetBlockInfoMethod__ {
+    name: 'getBlockInfo';
+    data: {
+        chain: string;
+        blockIndex: number;
+    };
+}
+
+export interface __GetBlockRequestIdsMethod__ {
+    name: 'getBlockRequestIds';
+    data: {
+        chain: string;
+        blockIndex: number;
+    };
+}
+
+export interface __GetBlockReceiptsMethod__ {
+    name: 'getBlockReceipts';
+    data: {
+        chain: string;
+        blockIndex: number;
+    };
+}
+
+export interface __IsRequestProcessedMethod__ {
+    name: 'isRequestProcessed';
+    data: {
+        chain: string;
+        requestId: OutputId;
+    };
+}
+
+export interface __GetEventsForBlockMethod__ {
+    name: 'getEventsForBlock';
+    data: {
+        chain: string;
+        blockIndex: number;
+    };
+}
+
+export interface __GetEventsForRequestMethod__ {
+    name: 'getEventsForRequest';
+    data: {
+        chain: string;
+        requestId: OutputId;
+    };
+}
+
+export interface __GetEventsForContractMethod__ {
+    name: 'getEventsForContract';
+    data: {
+        chain: string;
+        contractHname: number;
+    };
+}
+
+export interface __GetCacheStatsMethod__ {
+    name: 'getCacheStats';
+}
@@ -1,14 +1,74 @@
 import type {
     __GetInfoMethod__,
+    __GetChainsMethod__,
+    __GetChainInfoMethod__,
+    __GetCommitteeInfoMethod__,
+    __CallViewMethod__,
     __GetBalanceMethod__,
+    __GetAccountNonceMethod__,
+    __GetAccountNftsMethod__,
+    __GetAccountFoundriesMethod__,
+    __GetAccountNativeTokensMethod__,
+    __GetNftDataMethod__,
+    __GetNativeTokenIdsMethod__,
+    __GetTotalAssetsMethod__,
+    __GetAccountsMethod__,
+    __GetAllAccountsMethod__,
+    __GetFoundryOutputMethod__,
     __GetReceiptMethod__,
+    __ResolveVmErrorMethod__,
+    __SubmitOffLedgerMethod__,
+    __WaitForRequestMethod__,
+    __GetStateValueMethod__,
+    __GetBlobInfoMethod__,
+    __GetBlobFieldMethod__,
+    __PostEstimateGasForRequestMethod__,
     __PostEstimateGasOffLedgerMethod__,
     __PostEstimateGasOnLedgerMethod__,
+    __GetLatestBlockInfoMethod__,
+    __GetBlockInfoMethod__,
+    __GetBlockRequestIdsMethod__,
+    __GetBlockReceiptsMethod__,
+    __IsRequestProcessedMethod__,
+    __GetEventsForBlockMethod__,
+    __GetEventsForRequestMethod__,
+    __GetEventsForContractMethod__,
+    __GetCacheStatsMethod__,
 } from './api';
 
 export type __ApiMethods__ =
     | __GetInfoMethod__
+    | __GetChainsMethod__
+    | __GetChainInfoMethod__
+    | __GetCommitteeInfoMethod__
+    | __CallViewMethod__
     | __GetBalanceMethod__
+    | __GetAccountNonceMethod__
+    | __GetAccountNftsMethod__
+    | __GetAccountFoundriesMethod__
+    | __GetAccountNativeTokensMethod__
+    | __GetNftDataMethod__
+    | __GetNativeTokenIdsMethod__
+    | __GetTotalAssetsMethod__
+    | __GetAccountsMethod__
+    | __GetAllAccountsMethod__
+    | __GetFoundryOutputMethod__
     | __GetReceiptMethod__
+    | __ResolveVmErrorMethod__
+    | __SubmitOffLedgerMethod__
+    | __WaitForRequestMethod__
+    | __GetStateValueMethod__
+    | __GetBlobInfoMethod__
+    | __GetBlobFieldMethod__
+    | __PostEstimateGasForRequestMethod__
     | __PostEstimateGasOffLedgerMethod__
-    | __PostEstimateGasOnLedgerMethod__;
+    | __PostEstimateGasOnLedgerMethod__
+    | __GetLatestBlockInfoMethod__
+    | __GetBlockInfoMethod__
+    | __GetBlockRequestIdsMethod__
+    | __GetBlockReceiptsMethod__
+    | __IsRequestProcessedMethod__
+    | __GetEventsForBlockMethod__
+    | __GetEventsForRequestMethod__
+    | __GetEventsForContractMethod__
+    | __GetCacheStatsMethod__;
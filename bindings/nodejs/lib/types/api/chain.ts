@@ -0,0 +1,64 @@
+export interface ChainSummary {
+    chainID: string;
+    isActive: boolean;
+}
+
+export interface Ratio32 {
+    a: number;
+    b: number;
+}
+
+export interface GasFeePolicy {
+    gasPerToken: Ratio32;
+    evmGasRatio: Ratio32;
+    validatorFeeShare: number;
+}
+
+export interface GasLimits {
+    maxGasPerBlock: number;
+    minGasPerRequest: number;
+    maxGasPerRequest: number;
+    maxGasExternalViewCall: number;
+}
+
+export interface ChainMetadata {
+    evmJsonRpcURL: string;
+    evmWebSocketURL: string;
+    name: string;
+    description: string;
+    website: string;
+}
+
+export interface ChainInfo {
+    chainID: string;
+    chainOwnerId: string;
+    evmChainId: number;
+    gasFeePolicy: GasFeePolicy;
+    gasLimits: GasLimits;
+    isActive: boolean;
+    metadata: ChainMetadata;
+    publicURL: string;
+}
+
+export interface PeeringNodeStatus {
+    isAlive: boolean;
+    isTrusted: boolean;
+    name: string;
+    numUsers: number;
+    peeringURL: string;
+    publicKey: string;
+}
+
+export interface CommitteeNode {
+    accessAPI: string;
+    node: PeeringNodeStatus;
+}
+
+export interface CommitteeInfo {
+    chainId: string;
+    active: boolean;
+    stateAddress: string;
+    committeeNodes: CommitteeNode[];
+    accessNodes: CommitteeNode[];
+    candidateNodes: CommitteeNode[];
+}
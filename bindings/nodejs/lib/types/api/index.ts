@@ -3,3 +3,5 @@
 
 export * from './bridge';
 export * from './info';
+export * from './chain';
+export * from './accounts';
@@ -1,4 +1,4 @@
-import { OutputId } from "@iota/sdk";
+import { HexEncodedString, OutputId } from "@iota/sdk";
 import { Assets } from "../assets";
 
 export interface RentStructure {
@@ -35,10 +35,31 @@ export interface L1Params {
 export interface WaspInfo {
     peeringUrl: string;
     l1Params: L1Params;
-    publicKey: number; // Assuming f64 maps to number in TypeScript
+    publicKey: HexEncodedString;
     version: string;
 }
 
+export interface BlockInfo {
+    blockIndex: number;
+    timestamp: string;
+    totalRequests: number;
+    numSuccessfulRequests: number;
+    numOffLedgerRequests: number;
+    previousAliasOutput: string;
+    gasBurned: string;
+    gasFeeCharged: string;
+}
+
+export interface Event {
+    /** The hname of the contract that emitted the event. */
+    contractID: number;
+    topic: string;
+    /** Unix timestamp in nanoseconds. */
+    timestamp: number;
+    /** Hex encoded payload. */
+    payload: string;
+}
+
 export interface GasBurned {
     code: number;
     gasBurned: number;
@@ -49,6 +70,18 @@ export interface NodeError {
     params: string[];
 }
 
+export interface VmErrorCode {
+    contractHname: number;
+    id: number;
+}
+
+export interface VmError {
+    code: VmErrorCode;
+    params: string[];
+    /** The message template of the error formatted with its params. */
+    message: string;
+}
+
 export interface Target {
     contractHName: string;
     functionHName: string;
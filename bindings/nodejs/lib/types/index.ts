@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 export * from './api';
+export * from './api-options';
 export * from './address';
 export * from './contracts';
 export * from './assets';
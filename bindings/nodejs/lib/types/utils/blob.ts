@@ -0,0 +1,10 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/**
+ * A set of named fields stored as one blob by the blob contract.
+ * Store it by calling the `storeBlob` entry point of the `blob` contract with the fields as params.
+ */
+export interface Blob {
+    fields: Record<string, number[]>;
+}
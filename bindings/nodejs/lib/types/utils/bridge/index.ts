@@ -1,10 +1,14 @@
 import type {
     __EthereumAgentIdMethod__,
     __SpecialEncodeMethod__,
+    __OffLedgerRequestEssenceMethod__,
+    __BlobHashMethod__,
     __HnameMethod__,
 } from './utils';
 
 export type __UtilsMethods__ =
     | __EthereumAgentIdMethod__
     | __SpecialEncodeMethod__
+    | __OffLedgerRequestEssenceMethod__
+    | __BlobHashMethod__
     | __HnameMethod__;
@@ -1,4 +1,4 @@
-import { RequestMetadata } from '../..';
+import { Blob, OffLedgerRequest, RequestMetadata } from '../..';
 import { EvmAddress } from '../../address';
 
 export interface __EthereumAgentIdMethod__ {
@@ -16,6 +16,20 @@ export interface __SpecialEncodeMethod__ {
     };
 }
 
+export interface __OffLedgerRequestEssenceMethod__ {
+    name: 'offLedgerRequestEssence';
+    data: {
+        request: OffLedgerRequest;
+    };
+}
+
+export interface __BlobHashMethod__ {
+    name: 'blobHash';
+    data: {
+        blob: Blob;
+    };
+}
+
 export interface __HnameMethod__ {
     name: 'hname';
     data: {
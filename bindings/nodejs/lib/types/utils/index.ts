@@ -3,4 +3,6 @@
 
 export * from './bridge';
 export * from './request-metadata';
+export * from './off-ledger-request';
 export * from './contract-identity';
+export * from './blob';
@@ -0,0 +1,38 @@
+import { Assets } from '../assets';
+import { Utils } from '../../utils';
+
+import { HexEncodedString } from '@iota/sdk';
+import { Contract } from '../contracts';
+
+export class OffLedgerRequest {
+    readonly chainId: HexEncodedString;
+    readonly targetContract: number;
+    readonly targetEntryPoint: number;
+    readonly params: Map<string, Uint8Array> = new Map();
+    readonly nonce: bigint;
+    readonly gasBudget: bigint;
+    readonly allowance: Assets = new Assets();
+    publicKey?: HexEncodedString;
+    signature?: HexEncodedString;
+
+    constructor(
+        chainId: HexEncodedString,
+        targetContract: Contract,
+        targetEntryPoint: string,
+        nonce: bigint,
+        gasBudget: bigint,
+    ) {
+        this.chainId = chainId;
+        this.targetContract = Utils.hname(targetContract);
+        this.targetEntryPoint = Utils.hname(targetEntryPoint);
+        this.nonce = nonce;
+        this.gasBudget = gasBudget;
+    }
+
+    /**
+     * The hex encoded bytes that have to be signed with ed25519.
+     */
+    essence(): HexEncodedString {
+        return '0x' + Utils.offLedgerRequestEssence(this);
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import { callUtilsMethod } from '../bindings';
-import { RequestMetadata } from '../types';
+import { Blob, OffLedgerRequest, RequestMetadata } from '../types';
 import { EvmAddress } from '../types/address';
 
 /** Utils class for utils. */
@@ -16,6 +16,29 @@ export class Utils {
         });
     }
 
+    static offLedgerRequestEssence(request: OffLedgerRequest): string {
+        return callUtilsMethod({
+            name: 'offLedgerRequestEssence',
+            data: {
+                request,
+            },
+        });
+    }
+
+    /**
+     * Compute the hash the chain identifies a blob with. For a program this is its program hash.
+     *
+     * @returns The hex encoded hash.
+     */
+    static blobHash(blob: Blob): string {
+        return callUtilsMethod({
+            name: 'blobHash',
+            data: {
+                blob,
+            },
+        });
+    }
+
     static hname(name: string): number {
         return callUtilsMethod({
             name: 'hname',
@@ -5,8 +5,8 @@ use std::sync::Arc;
 
 use iota_sdk_evm_bindings_core::{
     call_api_method as rust_call_api_method,
-    iota_sdk::{client::{Error as ClientError}, Url},
-    ApiMethod, Response, iota_sdk_evm::Api, iota_sdk_evm::Error
+    iota_sdk_evm::{Api, ApiBuilder},
+    ApiMethod, Response,
 };
 use napi::{bindgen_prelude::External, Result};
 use napi_derive::napi;
@@ -17,8 +17,11 @@ use crate::{build_js_error, destroyed_err, NodejsError};
 pub type ApiMethodHandler = Arc<RwLock<Option<Api>>>;
 
 #[napi(js_name = "createApi")]
-pub async fn create_api(url: String) -> Result<External<ApiMethodHandler>> {
-    let api = Api::new(Url::parse(&url).map_err(|e| NodejsError::new(Error::ClientError(ClientError::Url(e))))?);
+pub async fn create_api(options: String) -> Result<External<ApiMethodHandler>> {
+    let api = ApiBuilder::new()
+        .from_json(&options)
+        .and_then(ApiBuilder::finish)
+        .map_err(NodejsError::new)?;
     Ok(External::new(Arc::new(RwLock::new(Some(api)))))
 }
 
@@ -5,8 +5,8 @@ use std::sync::Arc;
 
 use iota_sdk_evm_bindings_core::{
     call_api_method as rust_call_api_method,
-    iota_sdk::{client::{Error as ClientError}, Url},
-    Response, iota_sdk_evm::{Error, Api}
+    iota_sdk_evm::{Api, ApiBuilder},
+    Response,
 };
 use tokio::sync::RwLock;
 use wasm_bindgen::{prelude::wasm_bindgen, JsError};
@@ -19,8 +19,8 @@ pub struct ApiMethodHandler(Arc<RwLock<Option<Api>>>);
 
 /// Creates a method handler with the given client options.
 #[wasm_bindgen(js_name = createApi)]
-pub async fn create_client(url: String) -> Result<ApiMethodHandler, JsError> {
-    let api = Api::new(Url::parse(&url).map_err(|e| Error::ClientError(ClientError::Url(e)))?);
+pub async fn create_client(options: String) -> Result<ApiMethodHandler, JsError> {
+    let api = ApiBuilder::new().from_json(&options)?.finish()?;
 
     Ok(ApiMethodHandler(Arc::new(RwLock::new(Some(api)))))
 }
@@ -15,7 +15,6 @@ use iota_sdk::{
     client::{
         constants::SHIMMER_COIN_TYPE,
         secret::{stronghold::StrongholdSecretManager, SecretManager},
-        Client,
     },
     crypto::keys::bip39::Mnemonic,
     packable::PackableExt,
@@ -26,10 +25,12 @@ use iota_sdk::{
             unlock_condition::AddressUnlockCondition,
             BasicOutputBuilder, Feature, OutputId,
         },
-        payload::transaction::TransactionId,
-        BlockId,
+        payload::transaction::TransactionEssence,
+    },
+    wallet::{
+        account::types::{AccountAddress, Transaction},
+        Account, ClientOptions,
     },
-    wallet::{account::types::AccountAddress, Account, ClientOptions},
     Wallet,
 };
 use iota_sdk_evm::{
@@ -108,22 +109,26 @@ async fn main() -> Result<()> {
         // let _ = send_to_evm(&account, to_send, account_addr, Some(&evm_addr)).await?;
 
         // Send on our own l2 linked account
-        let _ = send_to_evm(&account, to_send, account_addr, None).await?;
+        let request_id = send_to_evm(&account, to_send, account_addr, None).await?;
 
-        // Wasp node updates after at most 1 more milestone
-        println!("await 1 milestone...");
-        one_milestone(account.client()).await?;
+        println!("await request processing...");
+        let receipt = api
+            .wait_for_request(TESTNET_CHAIN_ADDRESS, request_id, Duration::from_secs(30))
+            .await?;
+        println!("Request processed in block {}", receipt.block_index);
 
         let assets_post = api.get_balance(TESTNET_CHAIN_ADDRESS, *account_addr.address()).await?;
         println!("EVM balance post: '{:?}'", assets_post);
 
         println!("------[ WITHDRAW ]---------");
 
-        let _ = withdraw_from_evm(&account, &api, assets_post.base_tokens, account_addr).await?;
+        let request_id = withdraw_from_evm(&account, &api, assets_post.base_tokens, account_addr).await?;
 
-        // Wasp node updates after at most 1 more milestone
-        println!("await 1 milestone...");
-        one_milestone(account.client()).await?;
+        println!("await request processing...");
+        let receipt = api
+            .wait_for_request(TESTNET_CHAIN_ADDRESS, request_id, Duration::from_secs(30))
+            .await?;
+        println!("Request processed in block {}", receipt.block_index);
 
         let assets_post = api.get_balance(TESTNET_CHAIN_ADDRESS, *account_addr.address()).await?;
         println!("EVM balance post withdraw: '{:?}'", assets_post);
@@ -137,7 +142,7 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-async fn withdraw_from_evm(account: &Account, api: &Api, amount: u64, from_addr: &AccountAddress) -> Result<BlockId> {
+async fn withdraw_from_evm(account: &Account, api: &Api, amount: u64, from_addr: &AccountAddress) -> Result<OutputId> {
     let protocol_parameters = account.client().get_protocol_parameters().await?;
     let mut metadata = withdraw(amount);
     // let gas_fee = api.estimate_gas_off_ledger(TESTNET_CHAIN_ADDRESS, &metadata).await?;
@@ -180,7 +185,7 @@ async fn withdraw_from_evm(account: &Account, api: &Api, amount: u64, from_addr:
         transaction.transaction_id
     );
 
-    wait(account, &transaction.transaction_id).await
+    wait(account, &transaction).await
 }
 
 async fn send_to_evm(
@@ -188,7 +193,7 @@ async fn send_to_evm(
     amount: u64,
     from_addr: &AccountAddress,
     to_address: Option<&EvmAddress>,
-) -> Result<BlockId> {
+) -> Result<OutputId> {
     let protocol_parameters = account.client().get_protocol_parameters().await?;
     let metadata = match to_address {
         Some(a) => deposit_to(amount, a),
@@ -216,26 +221,41 @@ async fn send_to_evm(
         transaction.transaction_id
     );
 
-    wait(account, &transaction.transaction_id).await
-}
-
-async fn one_milestone(_client: &Client) -> Result<()> {
-    // we cheat
-    let duration = Duration::from_secs(3);
-    tokio::time::sleep(duration).await;
-    Ok(())
+    wait(account, &transaction).await
 }
 
-async fn wait(account: &Account, tx: &TransactionId) -> Result<BlockId> {
+// Returns the ID of the request output, the output of the transaction that is unlocked by the chain
+async fn wait(account: &Account, transaction: &Transaction) -> Result<OutputId> {
     // Wait for transaction to get included
-    let block_id = account.retry_transaction_until_included(tx, None, None).await?;
+    let block_id = account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
 
     println!(
         "Block included: {}/block/{}",
         std::env::var("EXPLORER_URL").unwrap(),
         block_id
     );
-    Ok(block_id)
+
+    // The wallet may add a remainder output, so the request isn't necessarily the first output
+    let chain_address = Bech32Address::from_str(TESTNET_CHAIN_ADDRESS)?;
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    let index = essence
+        .outputs()
+        .iter()
+        .position(|output| {
+            output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.address())
+                .map_or(false, |unlock_condition| {
+                    unlock_condition.address() == chain_address.inner()
+                })
+        })
+        .ok_or(iota_sdk_evm::Error::IO {
+            expected: std::io::ErrorKind::NotFound,
+            message: "the transaction has no output for the chain",
+        })?;
+    Ok(OutputId::new(transaction.transaction_id, index as u16)?)
 }
 
 fn withdraw(amount: u64) -> RequestMetadata {
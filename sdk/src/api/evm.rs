@@ -0,0 +1,345 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Ethereum JSON-RPC client for the EVM of a chain
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use iota_sdk::U256;
+pub use primitive_types::{H160, H256};
+use serde::{de::DeserializeOwned, Deserialize, Serialize, Serializer};
+
+use crate::{Api, Error, EvmAddress, Result};
+
+/// The JSON-RPC error code for an internal error.
+const INTERNAL_ERROR: i64 = -32603;
+
+/// Converts an [`EvmAddress`] to the address type used in JSON-RPC messages.
+pub fn h160(address: &EvmAddress) -> H160 {
+    H160((*address).into())
+}
+
+/// Identifies the block a call is executed on.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum BlockNumber {
+    #[default]
+    Latest,
+    Earliest,
+    Pending,
+    Number(u64),
+}
+
+impl Serialize for BlockNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Latest => serializer.serialize_str("latest"),
+            Self::Earliest => serializer.serialize_str("earliest"),
+            Self::Pending => serializer.serialize_str("pending"),
+            Self::Number(number) => serializer.serialize_str(&format!("{number:#x}")),
+        }
+    }
+}
+
+/// A message call as used by `eth_call` and `eth_estimateGas`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<H160>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<H160>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas: Option<U256>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<U256>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<U256>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        with = "iota_sdk::utils::serde::prefix_hex_bytes"
+    )]
+    pub data: Vec<u8>,
+}
+
+impl CallRequest {
+    /// Creates a call of a contract with the given ABI encoded input.
+    pub fn new(to: &EvmAddress, data: Vec<u8>) -> Self {
+        Self {
+            to: Some(h160(to)),
+            data,
+            ..Default::default()
+        }
+    }
+}
+
+/// A filter for `eth_getLogs`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_block: Option<BlockNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_block: Option<BlockNumber>,
+    /// Only logs of blocks with this hash, can't be combined with `from_block` and `to_block`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<H256>,
+    /// Only logs emitted by one of these contracts.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub address: Vec<H160>,
+    /// Topics by position, `None` matches any topic.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<Option<H256>>,
+}
+
+/// A log emitted by a contract.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Log {
+    pub address: H160,
+    pub topics: Vec<H256>,
+    #[serde(with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+    pub data: Vec<u8>,
+    pub block_hash: Option<H256>,
+    pub block_number: Option<U256>,
+    pub transaction_hash: Option<H256>,
+    pub transaction_index: Option<U256>,
+    pub log_index: Option<U256>,
+    #[serde(default)]
+    pub removed: bool,
+}
+
+/// The receipt of an EVM transaction.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    pub transaction_hash: H256,
+    pub transaction_index: U256,
+    pub block_hash: H256,
+    pub block_number: U256,
+    pub from: H160,
+    pub to: Option<H160>,
+    pub cumulative_gas_used: U256,
+    pub gas_used: U256,
+    pub effective_gas_price: Option<U256>,
+    pub contract_address: Option<H160>,
+    pub logs: Vec<Log>,
+    #[serde(with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+    pub logs_bloom: Vec<u8>,
+    /// 1 if the transaction succeeded, 0 if it reverted.
+    pub status: Option<U256>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonRpcResponse<T> {
+    #[serde(default)]
+    result: Option<T>,
+    #[serde(default)]
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+/// Client for the Ethereum JSON-RPC endpoint of a chain, sending its requests through an [`Api`].
+pub struct EvmRpc<'a> {
+    api: &'a Api,
+    chain: String,
+    next_id: AtomicU64,
+}
+
+impl Api {
+    /// Returns a client for the Ethereum JSON-RPC endpoint of a chain.
+    pub fn evm_rpc(&self, chain: &str) -> EvmRpc<'_> {
+        EvmRpc {
+            api: self,
+            chain: chain.to_string(),
+            next_id: AtomicU64::new(1),
+        }
+    }
+}
+
+impl EvmRpc<'_> {
+    /// Returns the EVM chain ID.
+    pub async fn chain_id(&self) -> Result<u64> {
+        let chain_id: U256 = self.request("eth_chainId", serde_json::json!([])).await?;
+        if chain_id > U256::from(u64::MAX) {
+            return Err(Error::EvmRpc {
+                code: INTERNAL_ERROR,
+                message: format!("eth_chainId returned {chain_id:#x}, which doesn't fit into 64 bits"),
+            });
+        }
+        Ok(chain_id.as_u64())
+    }
+
+    /// Returns the balance of an address in wei.
+    pub async fn get_balance(&self, address: &EvmAddress, block: BlockNumber) -> Result<U256> {
+        self.request("eth_getBalance", serde_json::json!([h160(address), block]))
+            .await
+    }
+
+    /// Returns the number of transactions sent from an address, which is the nonce of its next transaction.
+    pub async fn get_transaction_count(&self, address: &EvmAddress, block: BlockNumber) -> Result<U256> {
+        self.request("eth_getTransactionCount", serde_json::json!([h160(address), block]))
+            .await
+    }
+
+    /// Executes a message call without creating a transaction and returns its output.
+    pub async fn call(&self, call: &CallRequest, block: BlockNumber) -> Result<Vec<u8>> {
+        let output: String = self.request("eth_call", serde_json::json!([call, block])).await?;
+        Ok(prefix_hex::decode(output)?)
+    }
+
+    /// Estimates the gas a message call needs.
+    pub async fn estimate_gas(&self, call: &CallRequest) -> Result<U256> {
+        self.request("eth_estimateGas", serde_json::json!([call])).await
+    }
+
+    /// Submits a signed, RLP encoded transaction and returns its hash.
+    pub async fn send_raw_transaction(&self, transaction: &[u8]) -> Result<H256> {
+        self.request(
+            "eth_sendRawTransaction",
+            serde_json::json!([prefix_hex::encode(transaction)]),
+        )
+        .await
+    }
+
+    /// Returns the receipt of a transaction, or `None` if it wasn't processed yet.
+    pub async fn get_transaction_receipt(&self, transaction_hash: H256) -> Result<Option<TransactionReceipt>> {
+        self.request_optional("eth_getTransactionReceipt", serde_json::json!([transaction_hash]))
+            .await
+    }
+
+    /// Returns the logs matching a filter.
+    pub async fn get_logs(&self, filter: &LogFilter) -> Result<Vec<Log>> {
+        self.request("eth_getLogs", serde_json::json!([filter])).await
+    }
+
+    async fn request<T: DeserializeOwned + core::fmt::Debug + Serialize>(
+        &self,
+        method: &'static str,
+        params: serde_json::Value,
+    ) -> Result<T> {
+        self.request_optional(method, params).await?.ok_or(Error::EvmRpc {
+            code: INTERNAL_ERROR,
+            message: format!("{method} returned no result"),
+        })
+    }
+
+    /// Sends a JSON-RPC request to the node.
+    /// POST /v1/chains/{chainID}/evm
+    async fn request_optional<T: DeserializeOwned + core::fmt::Debug + Serialize>(
+        &self,
+        method: &'static str,
+        params: serde_json::Value,
+    ) -> Result<Option<T>> {
+        let path = &format!("v1/chains/{}/evm", self.chain);
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": self.next_id.fetch_add(1, Ordering::Relaxed),
+            "method": method,
+            "params": params,
+        });
+
+        // A transaction the node already accepted is rejected when it is sent again
+        let may_resend = method != "eth_sendRawTransaction";
+        let res: JsonRpcResponse<T> = self
+            .api
+            .post_request(path, None, request, false, false, may_resend)
+            .await?;
+        match res.error {
+            Some(error) => Err(Error::EvmRpc {
+                code: error.code,
+                message: error.message,
+            }),
+            None => Ok(res.result),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_sdk::U256;
+
+    use super::JsonRpcResponse;
+    use crate::{
+        api::node::transport::fake::{api, FakeTransport},
+        h160, BlockNumber, CallRequest, Error, EvmAddress, TransactionReceipt,
+    };
+
+    #[tokio::test]
+    async fn serialize_call() {
+        let call = CallRequest::new(&EvmAddress::from([0x10; 20]), vec![0xab, 0xcd]);
+        assert_eq!(
+            serde_json::json!([call, BlockNumber::Number(255)]),
+            serde_json::json!([
+                { "to": "0x1010101010101010101010101010101010101010", "data": "0xabcd" },
+                "0xff"
+            ])
+        );
+        assert_eq!(serde_json::json!(BlockNumber::Latest), serde_json::json!("latest"));
+    }
+
+    #[tokio::test]
+    async fn deserialize_receipt() {
+        let res = serde_json::from_value::<JsonRpcResponse<TransactionReceipt>>(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "transactionHash": "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060",
+                "transactionIndex": "0x0",
+                "blockHash": "0x7ca7f8d30c8bda0a3e3f8ea1ae6a3f07ef9c2e3eaa0d2b0d8bc1d8b2a4d3f4c1",
+                "blockNumber": "0x2a",
+                "from": "0x1010101010101010101010101010101010101010",
+                "to": null,
+                "cumulativeGasUsed": "0x5208",
+                "gasUsed": "0x5208",
+                "contractAddress": "0x2020202020202020202020202020202020202020",
+                "logs": [],
+                "logsBloom": "0x00",
+                "status": "0x1"
+            }
+        }))
+        .unwrap();
+
+        let receipt = res.result.unwrap();
+        assert_eq!(receipt.block_number, U256::from(42));
+        assert_eq!(receipt.from, h160(&EvmAddress::from([0x10; 20])));
+        assert_eq!(receipt.to, None);
+        assert_eq!(receipt.status, Some(U256::one()));
+        assert_eq!(receipt.effective_gas_price, None);
+
+        let res = serde_json::from_str::<JsonRpcResponse<TransactionReceipt>>(r#"{"id":1,"result":null}"#).unwrap();
+        assert!(res.result.is_none() && res.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn chain_id() {
+        let api = api(
+            &["http://localhost:9090"],
+            FakeTransport::json(200, r#"{"jsonrpc":"2.0","id":1,"result":"0x431"}"#),
+        );
+        assert_eq!(api.evm_rpc("chain").chain_id().await.unwrap(), 1073);
+
+        let api = api(
+            &["http://localhost:9090"],
+            FakeTransport::json(200, r#"{"jsonrpc":"2.0","id":1,"result":"0x10000000000000000"}"#),
+        );
+        assert!(matches!(
+            api.evm_rpc("chain").chain_id().await,
+            Err(Error::EvmRpc { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn raw_transaction_not_resent() {
+        let fake = FakeTransport::statuses(&[503, 200]);
+        let api = api(&["http://localhost:9090", "http://localhost:9091"], fake.clone());
+
+        assert!(api.evm_rpc("chain").send_raw_transaction(&[1, 2, 3]).await.is_err());
+        assert_eq!(fake.requests().len(), 1);
+    }
+}
@@ -1,8 +1,14 @@
 // Copyright 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod evm;
 mod node;
 mod responses;
+#[cfg(not(target_family = "wasm"))]
+mod ws;
 
+pub use evm::*;
 pub use node::*;
 pub use responses::*;
+#[cfg(not(target_family = "wasm"))]
+pub use ws::*;
@@ -0,0 +1,296 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Authentication and the admin routes of a node
+
+use std::collections::HashMap;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use instant::{Duration, SystemTime};
+use serde_json::Value;
+
+use super::Api;
+use crate::{LoginResponse, PeeringNodeIdentity, PeeringNodeStatus, Result, User, VersionResponse};
+
+/// How long before its expiry a token gets refreshed.
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// The JWTs obtained with [`Api::login`], together with the credentials to get new ones once they expire.
+pub(crate) struct Session {
+    username: String,
+    password: String,
+    /// The JWT issued by each node, by index.
+    pub(crate) jwts: HashMap<usize, String>,
+    /// Unix timestamp of the earliest `exp` claim, if the tokens have one.
+    expires_at: Option<u64>,
+}
+
+impl Session {
+    fn needs_refresh(&self) -> bool {
+        match self.expires_at {
+            Some(expires_at) => {
+                // A clock before 1970 can't tell, the node rejects the token once it expired
+                let now = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+                now + REFRESH_MARGIN.as_secs() >= expires_at
+            }
+            None => false,
+        }
+    }
+}
+
+/// Reads the `exp` claim of a JWT without verifying it, the node does that.
+fn jwt_expiry(jwt: &str) -> Option<u64> {
+    let payload = jwt.split('.').nth(1)?;
+    let claims: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).ok()?).ok()?;
+    claims.get("exp")?.as_u64()
+}
+
+impl Api {
+    /// Logs in to each node. The returned JWTs are sent with all following requests to the node that issued them and
+    /// renewed with the same credentials shortly before they expire. Fails if no node accepted the credentials.
+    /// POST /auth
+    pub async fn login(&self, username: &str, password: &str) -> Result<()> {
+        let json = serde_json::json!({
+            "username": username,
+            "password": password,
+        });
+
+        let mut jwts = HashMap::new();
+        let mut error = None;
+        for index in 0..self.node_manager.len() {
+            let node = self.node_for(index, "auth", None)?;
+            let res = match self
+                .http_client
+                .post_json(node, self.get_timeout(), json.clone(), true)
+                .await
+            {
+                Ok(r) => r.into_json::<LoginResponse>(),
+                Err(e) => Err(e),
+            };
+            match res {
+                Ok(res) => {
+                    jwts.insert(index, res.jwt);
+                }
+                Err(e) => {
+                    error.replace(e);
+                }
+            }
+        }
+        if let Some(e) = error.filter(|_| jwts.is_empty()) {
+            return Err(e);
+        }
+
+        *self.session.write().unwrap() = Some(Session {
+            username: username.to_string(),
+            password: password.to_string(),
+            expires_at: jwts.values().filter_map(|jwt| jwt_expiry(jwt)).min(),
+            jwts,
+        });
+        Ok(())
+    }
+
+    /// Forgets the JWTs and the credentials of the last login.
+    pub fn logout(&self) {
+        self.session.write().unwrap().take();
+    }
+
+    /// Logs in again if a token of the current session is about to expire. Concurrent requests wait for a single
+    /// login. If it fails, the requests are sent with the current tokens, which may still be accepted.
+    pub(crate) async fn refresh_session(&self) {
+        if self.session_credentials().is_none() {
+            return;
+        }
+        let _refresh = self.session_refresh.lock().await;
+        // Another request may have renewed the session while this one waited
+        if let Some((username, password)) = self.session_credentials() {
+            if let Err(e) = self.login(&username, &password).await {
+                log::warn!("renewing the session failed: {e}");
+            }
+        }
+    }
+
+    /// The credentials of the session if a token is about to expire.
+    fn session_credentials(&self) -> Option<(String, String)> {
+        match &*self.session.read().unwrap() {
+            Some(session) if session.needs_refresh() => Some((session.username.clone(), session.password.clone())),
+            _ => None,
+        }
+    }
+
+    /// Returns the configuration of the node.
+    /// GET /v1/node/config
+    pub async fn node_config(&self) -> Result<HashMap<String, Value>> {
+        self.get_request("v1/node/config", None, false, false).await
+    }
+
+    /// Returns the version of the node software.
+    /// GET /v1/node/version
+    pub async fn node_version(&self) -> Result<String> {
+        let res: VersionResponse = self.get_request("v1/node/version", None, false, false).await?;
+        Ok(res.version)
+    }
+
+    /// Returns the peering identity of the node.
+    /// GET /v1/node/peers/identity
+    pub async fn peering_identity(&self) -> Result<PeeringNodeIdentity> {
+        self.get_request("v1/node/peers/identity", None, false, false).await
+    }
+
+    /// Returns the peers of the node and their status.
+    /// GET /v1/node/peers
+    pub async fn peers(&self) -> Result<Vec<PeeringNodeStatus>> {
+        self.get_request("v1/node/peers", None, false, false).await
+    }
+
+    /// Returns the trusted peers of the node.
+    /// GET /v1/node/peers/trusted
+    pub async fn trusted_peers(&self) -> Result<Vec<PeeringNodeIdentity>> {
+        self.get_request("v1/node/peers/trusted", None, false, false).await
+    }
+
+    /// Trusts a peer.
+    /// POST /v1/node/peers/trusted
+    pub async fn trust_peer(&self, name: &str, peering_url: &str, public_key: &str) -> Result<()> {
+        let json = serde_json::json!({
+            "name": name,
+            "peeringURL": peering_url,
+            "publicKey": public_key,
+        });
+
+        self.post_request_empty("v1/node/peers/trusted", None, json).await
+    }
+
+    /// Distrusts a peer, given by its name or public key.
+    /// DELETE /v1/node/peers/trusted/{peer}
+    pub async fn distrust_peer(&self, peer: &str) -> Result<()> {
+        let path = &format!("v1/node/peers/trusted/{peer}");
+
+        self.delete_request(path, None).await
+    }
+
+    /// Returns the users of the node.
+    /// GET /v1/users
+    pub async fn users(&self) -> Result<Vec<User>> {
+        self.get_request("v1/users", None, false, false).await
+    }
+
+    /// Returns a user of the node.
+    /// GET /v1/users/{username}
+    pub async fn user(&self, username: &str) -> Result<User> {
+        let path = &format!("v1/users/{username}");
+
+        self.get_request(path, None, false, false).await
+    }
+
+    /// Adds a user to the node.
+    /// POST /v1/users
+    pub async fn add_user(&self, username: &str, password: &str, permissions: &[String]) -> Result<()> {
+        let json = serde_json::json!({
+            "username": username,
+            "password": password,
+            "permissions": permissions,
+        });
+
+        self.post_request_empty("v1/users", None, json).await
+    }
+
+    /// Deletes a user from the node.
+    /// DELETE /v1/users/{username}
+    pub async fn delete_user(&self, username: &str) -> Result<()> {
+        let path = &format!("v1/users/{username}");
+
+        self.delete_request(path, None).await
+    }
+
+    /// Changes the password of a user.
+    /// PUT /v1/users/{username}/password
+    pub async fn change_user_password(&self, username: &str, password: &str) -> Result<()> {
+        let path = &format!("v1/users/{username}/password");
+
+        self.put_request_empty(path, None, serde_json::json!({ "password": password }))
+            .await
+    }
+
+    /// Changes the permissions of a user.
+    /// PUT /v1/users/{username}/permissions
+    pub async fn change_user_permissions(&self, username: &str, permissions: &[String]) -> Result<()> {
+        let path = &format!("v1/users/{username}/permissions");
+
+        self.put_request_empty(path, None, serde_json::json!({ "permissions": permissions }))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use reqwest::header::AUTHORIZATION;
+
+    use super::{
+        super::transport::fake::{api, response, FakeTransport},
+        jwt_expiry,
+    };
+
+    fn jwt(exp: u64) -> String {
+        format!("e30.{}.c2ln", URL_SAFE_NO_PAD.encode(format!(r#"{{"exp":{exp}}}"#)))
+    }
+
+    /// Answers logins with a token that already expired first, then with the given status and a token that doesn't.
+    fn node(renew_status: u16) -> FakeTransport {
+        let logins = Arc::new(AtomicUsize::new(0));
+        FakeTransport::new(move |request, _| match request.url.path() {
+            "/auth" => match logins.fetch_add(1, Ordering::SeqCst) {
+                0 => Ok(response(200, &format!(r#"{{"jwt":"{}"}}"#, jwt(0)))),
+                _ => Ok(response(renew_status, &format!(r#"{{"jwt":"{}"}}"#, jwt(u64::MAX)))),
+            },
+            _ => Ok(response(200, "[]")),
+        })
+    }
+
+    fn logins(fake: &FakeTransport) -> usize {
+        fake.requests()
+            .iter()
+            .filter(|request| request.url.path() == "/auth")
+            .count()
+    }
+
+    #[tokio::test]
+    async fn expiry() {
+        // {"alg":"HS256","typ":"JWT"}.{"sub":"wasp","exp":1700000000}
+        let jwt = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.eyJzdWIiOiJ3YXNwIiwiZXhwIjoxNzAwMDAwMDAwfQ.c2ln";
+        assert_eq!(jwt_expiry(jwt), Some(1700000000));
+        assert_eq!(jwt_expiry("not a jwt"), None);
+    }
+
+    #[tokio::test]
+    async fn renews_once() {
+        let fake = node(200);
+        let api = api(&["http://localhost:9090"], fake.clone());
+        api.login("user", "password").await.unwrap();
+
+        let (a, b, c) = tokio::join!(api.chains(), api.chains(), api.chains());
+        assert!(a.is_ok() && b.is_ok() && c.is_ok());
+        assert_eq!(logins(&fake), 2);
+    }
+
+    #[tokio::test]
+    async fn renewal_failure_keeps_token() {
+        let fake = node(401);
+        let api = api(&["http://localhost:9090"], fake.clone());
+        api.login("user", "password").await.unwrap();
+
+        assert!(api.chains().await.is_ok());
+        let requests = fake.requests();
+        let request = requests.last().unwrap();
+        assert_eq!(request.url.path(), "/v1/chains");
+        assert_eq!(request.headers[AUTHORIZATION], format!("Bearer {}", jwt(0)).as_str());
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Builder of the Api instance
+
+use std::{collections::HashMap, sync::Arc};
+
+use instant::Duration;
+use iota_sdk::client::{
+    node_manager::{
+        builder::validate_url,
+        node::{Node, NodeAuth, NodeDto},
+    },
+    Error as ClientError,
+};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use super::{
+    http_client::HttpClient,
+    node_manager::{NodeManager, DEFAULT_MIN_QUORUM_SIZE, DEFAULT_QUORUM_THRESHOLD},
+    transport::{reqwest_error, ReqwestTransport},
+    Api, ResponseCache, RetryPolicy,
+};
+use crate::{Error, Result};
+
+const DEFAULT_API_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_USER_AGENT: &str = "evm_sdk";
+
+/// Builder to construct an [`Api`] with sensible default values. It (de)serializes as the options object the
+/// bindings create an api with.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[must_use]
+pub struct ApiBuilder {
+    /// The nodes to send requests to, the first one is the primary node
+    #[serde(default)]
+    pub nodes: Vec<NodeDto>,
+    /// If node quorum is enabled. Will compare the responses from multiple nodes and only returns the response if
+    /// `quorum_threshold`% of the nodes return the same one
+    #[serde(default)]
+    pub quorum: bool,
+    /// Minimum amount of nodes required for request when quorum is enabled
+    #[serde(default = "default_min_quorum_size")]
+    pub min_quorum_size: usize,
+    /// % of nodes that have to return the same response so it gets accepted
+    #[serde(default = "default_quorum_threshold")]
+    pub quorum_threshold: usize,
+    /// Timeout for API requests
+    #[serde(default = "default_api_timeout")]
+    pub api_timeout: Duration,
+    /// The User-Agent header for requests
+    #[serde(default = "default_user_agent")]
+    pub user_agent: String,
+    /// Headers sent with every request
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+    /// PEM encoded root certificate to trust in addition to the ones of the system, for nodes with a self-signed
+    /// certificate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_certificate: Option<String>,
+    /// Accepts any certificate, including expired and self-signed ones. Only use this for local development.
+    #[serde(default)]
+    pub accept_invalid_certificates: bool,
+    /// Proxy all requests are sent through
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<Url>,
+    /// How requests that failed for a temporary reason are retried
+    #[serde(default)]
+    pub retry_policy: RetryPolicy,
+    /// How many responses that never change are cached in memory, no cache is used if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_capacity: Option<usize>,
+}
+
+fn default_min_quorum_size() -> usize {
+    DEFAULT_MIN_QUORUM_SIZE
+}
+
+fn default_quorum_threshold() -> usize {
+    DEFAULT_QUORUM_THRESHOLD
+}
+
+fn default_api_timeout() -> Duration {
+    DEFAULT_API_TIMEOUT
+}
+
+fn default_user_agent() -> String {
+    DEFAULT_USER_AGENT.to_string()
+}
+
+impl Default for ApiBuilder {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            quorum: false,
+            min_quorum_size: DEFAULT_MIN_QUORUM_SIZE,
+            quorum_threshold: DEFAULT_QUORUM_THRESHOLD,
+            api_timeout: DEFAULT_API_TIMEOUT,
+            user_agent: default_user_agent(),
+            headers: HashMap::new(),
+            root_certificate: None,
+            accept_invalid_certificates: false,
+            proxy: None,
+            retry_policy: RetryPolicy::default(),
+            cache_capacity: None,
+        }
+    }
+}
+
+impl ApiBuilder {
+    /// Creates an api builder with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the fields from an api options JSON, like the one the bindings are created with
+    pub fn from_json(self, options: &str) -> Result<Self> {
+        Ok(serde_json::from_str(options)?)
+    }
+
+    /// Adds a node
+    pub fn with_node(self, url: &str) -> Result<Self> {
+        self.with_node_auth(url, None)
+    }
+
+    /// Adds a node that requires basic auth or a JWT
+    pub fn with_node_auth(mut self, url: &str, auth: impl Into<Option<NodeAuth>>) -> Result<Self> {
+        let url = validate_url(Url::parse(url).map_err(ClientError::Url)?)?;
+        self.nodes.push(NodeDto::Node(Node {
+            url,
+            auth: auth.into(),
+            disabled: false,
+        }));
+        Ok(self)
+    }
+
+    /// Adds a list of nodes
+    pub fn with_nodes(mut self, urls: &[&str]) -> Result<Self> {
+        for url in urls {
+            self = self.with_node(url)?;
+        }
+        Ok(self)
+    }
+
+    /// Sets if quorum is used
+    pub fn with_quorum(mut self, quorum: bool) -> Self {
+        self.quorum = quorum;
+        self
+    }
+
+    /// Sets the minimum amount of nodes required for a request with quorum
+    pub fn with_min_quorum_size(mut self, min_quorum_size: usize) -> Self {
+        self.min_quorum_size = min_quorum_size;
+        self
+    }
+
+    /// Sets the % of nodes that have to return the same response so it gets accepted
+    pub fn with_quorum_threshold(mut self, threshold: usize) -> Self {
+        self.quorum_threshold = threshold.min(100);
+        self
+    }
+
+    /// Sets the timeout of requests. Ignored in the browser.
+    pub fn with_api_timeout(mut self, timeout: Duration) -> Self {
+        self.api_timeout = timeout;
+        self
+    }
+
+    /// Sets the User-Agent header of requests
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Adds a header that is sent with every request
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Trusts a PEM encoded root certificate. Ignored in the browser.
+    pub fn with_root_certificate(mut self, pem: impl Into<String>) -> Self {
+        self.root_certificate = Some(pem.into());
+        self
+    }
+
+    /// Sets if invalid certificates are accepted. Ignored in the browser.
+    pub fn with_accept_invalid_certificates(mut self, accept: bool) -> Self {
+        self.accept_invalid_certificates = accept;
+        self
+    }
+
+    /// Sends all requests through a proxy. Ignored in the browser.
+    pub fn with_proxy(mut self, url: &str) -> Result<Self> {
+        self.proxy = Some(Url::parse(url).map_err(ClientError::Url)?);
+        Ok(self)
+    }
+
+    /// Sets how requests that failed for a temporary reason are retried
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Caches up to `capacity` responses that never change in memory. Use [`Api::with_cache`] to also keep them in a
+    /// storage.
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache_capacity = Some(capacity);
+        self
+    }
+
+    /// Builds the api
+    pub fn finish(self) -> Result<Api> {
+        let mut node_manager = NodeManager::new(
+            self.nodes
+                .iter()
+                .map(Node::from)
+                .filter(|node| !node.disabled)
+                .collect(),
+        )?;
+        if self.quorum {
+            node_manager.set_quorum(self.min_quorum_size, self.quorum_threshold);
+        }
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes()).map_err(|_| Error::InvalidHeader(name.clone()))?,
+                HeaderValue::from_str(value).map_err(|_| Error::InvalidHeader(name.clone()))?,
+            );
+        }
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_str(&self.user_agent).map_err(|_| Error::InvalidHeader(USER_AGENT.to_string()))?,
+        );
+        let client = reqwest::Client::builder();
+        #[cfg(not(target_family = "wasm"))]
+        let client = self.with_tls_and_proxy(client)?;
+        let transport = ReqwestTransport::new(client.build().map_err(reqwest_error)?);
+
+        Ok(Api {
+            node_manager,
+            http_client: HttpClient::new(Arc::new(transport), headers, self.retry_policy),
+            timeout: self.api_timeout,
+            error_message_formats: Default::default(),
+            session: Default::default(),
+            session_refresh: Default::default(),
+            cache: self.cache_capacity.map(ResponseCache::new),
+        })
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn with_tls_and_proxy(&self, mut client: reqwest::ClientBuilder) -> Result<reqwest::ClientBuilder> {
+        if let Some(pem) = &self.root_certificate {
+            client =
+                client.add_root_certificate(reqwest::Certificate::from_pem(pem.as_bytes()).map_err(reqwest_error)?);
+        }
+        if let Some(proxy) = &self.proxy {
+            client = client.proxy(reqwest::Proxy::all(proxy.clone()).map_err(reqwest_error)?);
+        }
+        Ok(client.danger_accept_invalid_certs(self.accept_invalid_certificates))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use instant::Duration;
+
+    use crate::ApiBuilder;
+
+    #[tokio::test]
+    async fn options() {
+        let builder = ApiBuilder::new()
+            .from_json(
+                r#"{
+                    "nodes": ["http://localhost:9090", { "url": "http://localhost:9091", "auth": { "jwt": "token" } }],
+                    "apiTimeout": { "secs": 30, "nanos": 0 },
+                    "headers": { "x-api-key": "key" }
+                }"#,
+            )
+            .unwrap();
+        assert_eq!(builder.nodes.len(), 2);
+        assert_eq!(builder.api_timeout, Duration::from_secs(30));
+        assert_eq!(builder.user_agent, "evm_sdk");
+
+        let json = serde_json::to_string(&builder).unwrap();
+        assert_eq!(ApiBuilder::new().from_json(&json).unwrap(), builder);
+        assert!(builder.finish().is_ok());
+
+        assert!(ApiBuilder::new().finish().is_err());
+        assert!(ApiBuilder::new().with_node("ws://localhost:9090").is_err());
+        assert!(ApiBuilder::new()
+            .with_node("http://localhost:9090")
+            .unwrap()
+            .with_header("invalid header", "value")
+            .finish()
+            .is_err());
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cache of the responses that never change once a node returned them, like receipts of processed requests
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+use futures::future::BoxFuture;
+use iota_sdk::client::storage::StorageAdapter;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Prefix of the keys of cached responses in a storage.
+const STORAGE_KEY_PREFIX: &str = "evm-response-cache/";
+
+/// How often the cache could answer a request.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// A storage that keeps cached responses across restarts. Failing reads and writes are logged and treated as misses,
+/// the node can always be asked again.
+trait CacheStorage: Send + Sync {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Vec<u8>>>;
+
+    fn set<'a>(&'a self, key: &'a str, value: Vec<u8>) -> BoxFuture<'a, ()>;
+}
+
+impl<S> CacheStorage for S
+where
+    S: StorageAdapter,
+    S::Error: core::fmt::Display,
+{
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Vec<u8>>> {
+        Box::pin(async move {
+            self.get_bytes(key)
+                .await
+                .map_err(|e| log::warn!("reading the cached response {key} failed: {e}"))
+                .ok()
+                .flatten()
+        })
+    }
+
+    fn set<'a>(&'a self, key: &'a str, value: Vec<u8>) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            if let Err(e) = self.set_bytes(key, &value).await {
+                log::warn!("caching the response {key} failed: {e}");
+            }
+        })
+    }
+}
+
+/// The least recently used responses, up to a capacity.
+#[derive(Default)]
+struct Lru {
+    values: HashMap<String, (Value, u64)>,
+    /// The keys by when they were used last.
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Lru {
+    fn get(&mut self, key: &str) -> Option<Value> {
+        let (value, last_used) = self.values.get_mut(key)?;
+        self.order.remove(last_used);
+        self.tick += 1;
+        *last_used = self.tick;
+        self.order.insert(self.tick, key.to_string());
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: String, value: Value, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+        if let Some((_, last_used)) = self.values.remove(&key) {
+            self.order.remove(&last_used);
+        }
+        while self.values.len() >= capacity {
+            match self.order.pop_first() {
+                Some((_, oldest)) => self.values.remove(&oldest),
+                None => break,
+            };
+        }
+        self.tick += 1;
+        self.order.insert(self.tick, key.clone());
+        self.values.insert(key, (value, self.tick));
+    }
+}
+
+/// Opt-in cache of the responses of an [`Api`](crate::Api) that never change, like receipts of processed requests,
+/// past blocks and blob fields. Keeps the least recently used responses in memory and optionally all of them in a
+/// storage, for example the `RocksdbStorageAdapter` of iota-sdk. Error message templates aren't part of it, the api
+/// always keeps them.
+pub struct ResponseCache {
+    capacity: usize,
+    entries: Mutex<Lru>,
+    storage: Option<Box<dyn CacheStorage>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ResponseCache {
+    /// Creates a cache that keeps up to `capacity` responses in memory.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Default::default(),
+            storage: None,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Also keeps the responses in a storage, so they survive restarts.
+    pub fn with_storage<S>(mut self, storage: S) -> Self
+    where
+        S: StorageAdapter + 'static,
+        S::Error: core::fmt::Display,
+    {
+        self.storage = Some(Box::new(storage));
+        self
+    }
+
+    /// Returns how often a response was or wasn't cached.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(crate) async fn get(&self, key: &str) -> Option<Value> {
+        let mut value = self.entries.lock().unwrap().get(key);
+        if value.is_none() {
+            if let Some(storage) = &self.storage {
+                value = storage
+                    .get(&format!("{STORAGE_KEY_PREFIX}{key}"))
+                    .await
+                    .and_then(|bytes| serde_json::from_slice::<Value>(&bytes).ok());
+                if let Some(value) = &value {
+                    self.entries
+                        .lock()
+                        .unwrap()
+                        .insert(key.to_string(), value.clone(), self.capacity);
+                }
+            }
+        }
+
+        match value {
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+        value
+    }
+
+    pub(crate) async fn insert(&self, key: &str, value: &Value) {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), value.clone(), self.capacity);
+        if let Some(storage) = &self.storage {
+            storage
+                .set(&format!("{STORAGE_KEY_PREFIX}{key}"), value.to_string().into_bytes())
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_sdk::wallet::storage::adapter::memory::Memory;
+    use serde_json::json;
+
+    use super::{CacheStats, ResponseCache};
+
+    #[tokio::test]
+    async fn lru() {
+        let cache = ResponseCache::new(2);
+        cache.insert("chain/a", &json!(1)).await;
+        cache.insert("chain/b", &json!(2)).await;
+        assert_eq!(cache.get("chain/a").await, Some(json!(1)));
+
+        // b was used least recently
+        cache.insert("chain/c", &json!(3)).await;
+        assert_eq!(cache.get("chain/b").await, None);
+        assert_eq!(cache.get("chain/a").await, Some(json!(1)));
+        assert_eq!(cache.get("chain/c").await, Some(json!(3)));
+        assert_eq!(cache.stats(), CacheStats { hits: 3, misses: 1 });
+
+        // Responses that don't fit in memory are still found in the storage
+        let cache = ResponseCache::new(0).with_storage(Memory::default());
+        cache.insert("chain/a", &json!(1)).await;
+        assert_eq!(cache.get("chain/a").await, Some(json!(1)));
+        assert_eq!(cache.get("chain/b").await, None);
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+    }
+}
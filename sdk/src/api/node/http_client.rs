@@ -3,115 +3,197 @@
 
 //! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
 
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
-use iota_sdk::client::{node_api::error::Error, node_manager::node::Node};
-use reqwest::RequestBuilder;
+use iota_sdk::client::{node_api::error::Error as NodeApiError, node_manager::node::Node};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+    Method,
+};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
-pub(crate) struct Response(reqwest::Response);
+use super::{
+    middleware::{Middleware, ResponseInfo},
+    retry::{self, RetryPolicy},
+    transport::{is_connect_error, HttpRequest, HttpResponse, Transport},
+};
+use crate::{Error, Result};
 
-impl Response {
-    pub(crate) fn status(&self) -> u16 {
-        self.0.status().as_u16()
-    }
+const JSON: &str = "application/json";
 
-    pub(crate) async fn into_json<T: DeserializeOwned>(self) -> Result<T, Error> {
-        self.0.json().await.map_err(Into::into)
-    }
+pub(crate) struct Response(HttpResponse);
 
-    #[cfg(not(target_family = "wasm"))]
-    pub(crate) async fn into_text(self) -> Result<String, Error> {
-        self.0.text().await.map_err(Into::into)
+impl Response {
+    pub(crate) fn status(&self) -> u16 {
+        self.0.status
     }
 
-    pub(crate) async fn into_bytes(self) -> Result<Vec<u8>, Error> {
-        self.0.bytes().await.map(|b| b.to_vec()).map_err(Into::into)
+    pub(crate) fn into_json<T: DeserializeOwned>(self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.0.body)?)
     }
 }
 
 #[derive(Clone)]
 pub(crate) struct HttpClient {
-    client: reqwest::Client,
-    pub(crate) user_agent: String,
+    transport: Arc<dyn Transport>,
+    /// Headers sent with every request, including the User-Agent.
+    headers: HeaderMap,
+    retry_policy: RetryPolicy,
+    /// Called for every attempt of every request, in the order they were added.
+    middleware: Vec<Arc<dyn Middleware>>,
 }
 
 impl HttpClient {
-    pub(crate) fn new(user_agent: String) -> Self {
+    pub(crate) fn new(transport: Arc<dyn Transport>, headers: HeaderMap, retry_policy: RetryPolicy) -> Self {
         Self {
-            client: reqwest::Client::new(),
-            user_agent,
+            transport,
+            headers,
+            retry_policy,
+            middleware: Vec::new(),
         }
     }
 
-    async fn parse_response(response: reqwest::Response, url: &url::Url) -> Result<Response, Error> {
-        let status = response.status();
-        if status.is_success() {
+    pub(crate) fn set_transport(&mut self, transport: Arc<dyn Transport>) {
+        self.transport = transport;
+    }
+
+    pub(crate) fn add_middleware(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middleware.push(middleware);
+    }
+
+    /// Whether a request may be sent again, after it failed or to another node. Requests that aren't `idempotent` are
+    /// only sent again if the retry policy allows it.
+    pub(crate) fn may_resend(&self, idempotent: bool) -> bool {
+        idempotent || self.retry_policy.retry_non_idempotent
+    }
+
+    fn parse_response(response: HttpResponse, url: &url::Url) -> core::result::Result<Response, NodeApiError> {
+        if (200..300).contains(&response.status) {
             Ok(Response(response))
+        } else if response.status == 404 {
+            Err(NodeApiError::NotFound(url.to_string()))
         } else {
-            let text = response.text().await?;
-
-            if status.as_u16() == 404 {
-                Err(Error::NotFound(url.to_string()))
-            } else {
-                Err(Error::ResponseError {
-                    code: status.as_u16(),
-                    text,
-                    url: url.to_string(),
-                })
-            }
+            Err(NodeApiError::ResponseError {
+                code: response.status,
+                text: String::from_utf8_lossy(&response.body).into_owned(),
+                url: url.to_string(),
+            })
         }
     }
 
-    fn build_request(&self, request_builder: RequestBuilder, node: &Node, _timeout: Duration) -> RequestBuilder {
-        let mut request_builder = request_builder.header(reqwest::header::USER_AGENT, &self.user_agent);
-
+    fn build_request(&self, method: Method, node: &Node, timeout: Duration) -> Result<HttpRequest> {
+        let mut headers = self.headers.clone();
         if let Some(node_auth) = &node.auth {
             if let Some(jwt) = &node_auth.jwt {
-                request_builder = request_builder.bearer_auth(jwt);
+                headers.insert(
+                    AUTHORIZATION,
+                    HeaderValue::from_str(&format!("Bearer {jwt}"))
+                        .map_err(|_| Error::InvalidHeader(AUTHORIZATION.to_string()))?,
+                );
             }
         }
-        #[cfg(not(target_family = "wasm"))]
-        {
-            request_builder = request_builder.timeout(_timeout);
+        Ok(HttpRequest {
+            method,
+            url: node.url.clone(),
+            headers,
+            body: None,
+            timeout,
+        })
+    }
+
+    /// Sends a request with the transport and retries it according to the retry policy if it failed for a reason
+    /// that is likely temporary. Requests that aren't `idempotent` are only retried if the policy allows it.
+    async fn send(&self, node: &Node, idempotent: bool, request: HttpRequest) -> Result<Response> {
+        let method = request.method.clone();
+        let retry = self.may_resend(idempotent);
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let mut attempt_request = request.clone();
+            for middleware in &self.middleware {
+                middleware.before_request(&mut attempt_request);
+            }
+            let url = attempt_request.url.clone();
+
+            let start_time = instant::Instant::now();
+            let res = self.transport.send(attempt_request).await;
+            let info = ResponseInfo {
+                method: &method,
+                url: &url,
+                status: res.as_ref().ok().map(|response| response.status),
+                latency: start_time.elapsed(),
+                body_size: res.as_ref().map_or(0, |response| response.body.len()),
+                attempt,
+                error: res.as_ref().err(),
+            };
+            if let Some(status) = info.status {
+                log::debug!("{method}: {:?} ms for {status} {}", info.latency.as_millis(), node.url);
+            }
+            for middleware in &self.middleware {
+                middleware.after_response(&info);
+            }
+
+            let (retryable, retry_after) = match &res {
+                Ok(response) => (
+                    retry && retry::is_transient_status(response.status),
+                    retry::retry_after(&response.headers),
+                ),
+                Err(e) => (retry && is_connect_error(e), None),
+            };
+            let delay = retryable
+                .then(|| self.retry_policy.delay(attempt, retry_after))
+                .flatten();
+            let res = res.and_then(|response| {
+                Self::parse_response(response, &node.url).map_err(|e| Error::ClientError(e.into()))
+            });
+
+            match (res, delay) {
+                (Err(e), Some(delay)) => {
+                    log::debug!("{method} {} failed, retrying in {delay:?}: {e}", node.url);
+                    retry::sleep(delay).await;
+                }
+                // Also if the last error can't be retried, like a 408 after a 503
+                (Err(e), None) if attempt > 1 => {
+                    return Err(Error::RetriesExhausted {
+                        attempts: attempt,
+                        source: Box::new(e),
+                    });
+                }
+                (res, _) => return res,
+            }
         }
-        request_builder
     }
 
-    pub(crate) async fn get(&self, node: Node, timeout: Duration) -> Result<Response, Error> {
-        let mut request_builder = self.client.get(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
-        let start_time = instant::Instant::now();
-        let resp = request_builder.send().await?;
-        log::debug!(
-            "GET: {:?} ms for {} {}",
-            start_time.elapsed().as_millis(),
-            resp.status(),
-            node.url
-        );
-        Self::parse_response(resp, &node.url).await
+    pub(crate) async fn get(&self, node: Node, timeout: Duration) -> Result<Response> {
+        let request = self.build_request(Method::GET, &node, timeout)?;
+        self.send(&node, true, request).await
     }
 
-    // Get with header: "accept", "application/vnd.iota.serializer-v2"
-    pub(crate) async fn get_bytes(&self, node: Node, timeout: Duration) -> Result<Response, Error> {
-        let mut request_builder = self.client.get(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
-        request_builder = request_builder.header("accept", "application/vnd.iota.serializer-v2");
-        let resp = request_builder.send().await?;
-        Self::parse_response(resp, &node.url).await
+    /// Posts JSON. Only retried if the request is `idempotent`, like a view call, or the retry policy allows
+    /// retrying other requests.
+    pub(crate) async fn post_json(
+        &self,
+        node: Node,
+        timeout: Duration,
+        json: Value,
+        idempotent: bool,
+    ) -> Result<Response> {
+        let mut request = self.build_request(Method::POST, &node, timeout)?;
+        request.headers.insert(CONTENT_TYPE, HeaderValue::from_static(JSON));
+        request.body = Some(serde_json::to_vec(&json)?);
+        self.send(&node, idempotent, request).await
     }
 
-    pub(crate) async fn post_json(&self, node: Node, timeout: Duration, json: Value) -> Result<Response, Error> {
-        let mut request_builder = self.client.post(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
-        Self::parse_response(request_builder.json(&json).send().await?, &node.url).await
+    pub(crate) async fn put_json(&self, node: Node, timeout: Duration, json: Value) -> Result<Response> {
+        let mut request = self.build_request(Method::PUT, &node, timeout)?;
+        request.headers.insert(CONTENT_TYPE, HeaderValue::from_static(JSON));
+        request.body = Some(serde_json::to_vec(&json)?);
+        self.send(&node, true, request).await
     }
 
-    pub(crate) async fn post_bytes(&self, node: Node, timeout: Duration, body: &[u8]) -> Result<Response, Error> {
-        let mut request_builder = self.client.post(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
-        request_builder = request_builder.header("Content-Type", "application/vnd.iota.serializer-v2");
-        Self::parse_response(request_builder.body(body.to_vec()).send().await?, &node.url).await
+    pub(crate) async fn delete(&self, node: Node, timeout: Duration) -> Result<Response> {
+        let request = self.build_request(Method::DELETE, &node, timeout)?;
+        self.send(&node, true, request).await
     }
 }
@@ -0,0 +1,87 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Hooks into the requests an api sends, for tracing, audit logs or extra headers
+
+use instant::Duration;
+use reqwest::Method;
+use url::Url;
+
+use super::transport::HttpRequest;
+use crate::Error;
+
+/// What a [`Middleware`] learns about a sent request.
+#[derive(Debug)]
+pub struct ResponseInfo<'a> {
+    /// The HTTP method of the request.
+    pub method: &'a Method,
+    /// The url of the request, it contains the credentials if the node uses basic auth.
+    pub url: &'a Url,
+    /// The status of the response, `None` if no response arrived.
+    pub status: Option<u16>,
+    /// How long it took from sending the request until the whole response arrived.
+    pub latency: Duration,
+    /// The size of the response body in bytes.
+    pub body_size: usize,
+    /// The attempt this was, starting at 1, see [`RetryPolicy`](crate::RetryPolicy).
+    pub attempt: u32,
+    /// The error if the request failed before a response arrived.
+    pub error: Option<&'a Error>,
+}
+
+/// Hooks that are called for every request an [`Api`](crate::Api) sends, see
+/// [`Api::with_middleware`](crate::Api::with_middleware). Retried requests call both hooks again for every attempt.
+pub trait Middleware: Send + Sync {
+    /// Called before a request is sent, can change it, for example to add a correlation ID header.
+    fn before_request(&self, _request: &mut HttpRequest) {}
+
+    /// Called after a response arrived or sending the request failed.
+    fn after_response(&self, _info: &ResponseInfo<'_>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use reqwest::header::HeaderValue;
+
+    use super::{
+        super::transport::fake::{api, FakeTransport},
+        Middleware, ResponseInfo,
+    };
+    use crate::HttpRequest;
+
+    /// Adds a correlation ID and records what it sees.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<(String, Option<u16>, usize)>>>);
+
+    impl Middleware for Recorder {
+        fn before_request(&self, request: &mut HttpRequest) {
+            request
+                .headers
+                .insert("x-correlation-id", HeaderValue::from_static("42"));
+        }
+
+        fn after_response(&self, info: &ResponseInfo<'_>) {
+            self.0.lock().unwrap().push((
+                format!("{} {}", info.method, info.url.path()),
+                info.status,
+                info.body_size,
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn hooks() {
+        let fake = FakeTransport::json(200, "[]");
+        let recorder = Recorder::default();
+        let api = api(&["http://localhost:9090"], fake.clone()).with_middleware(recorder.clone());
+
+        api.chains().await.unwrap();
+        assert_eq!(fake.requests()[0].headers["x-correlation-id"], "42");
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [("GET /v1/chains".to_string(), Some(200), 2)]
+        );
+    }
+}
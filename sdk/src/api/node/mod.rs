@@ -1,42 +1,137 @@
 // Copyright 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod admin;
+mod builder;
+mod cache;
 // TODO use sdk
 pub(crate) mod http_client;
+mod middleware;
+mod node_manager;
+mod retry;
+pub(crate) mod transport;
 
 /// Structs for nodes
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug, future::Future, sync::RwLock};
 
 use instant::Duration;
 use iota_sdk::{
-    client::node_manager::node::Node,
-    packable::PackableExt,
-    types::block::{address::Bech32Address, output::OutputId},
+    client::{
+        node_api::error::Error as NodeApiError,
+        node_manager::node::{Node, NodeAuth, NodeDto},
+        Error as ClientError,
+    },
+    packable::{Packable, PackableExt},
+    types::block::{
+        address::{Address, AliasAddress, Bech32Address},
+        output::{FoundryOutput, NativeToken, NftId, Output, OutputId, TokenId},
+    },
 };
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 use url::Url;
 
-use self::http_client::HttpClient;
-use crate::{AssetsDto, ReceiptResponse, RequestMetadata, Result, WaspInfo};
+use self::{
+    admin::Session,
+    http_client::{HttpClient, Response},
+    node_manager::{is_node_route, NodeManager},
+};
+pub use self::{
+    builder::ApiBuilder,
+    cache::{CacheStats, ResponseCache},
+    middleware::{Middleware, ResponseInfo},
+    retry::RetryPolicy,
+    transport::{HttpRequest, HttpResponse, ReqwestTransport, Transport, TransportFuture},
+};
+use crate::{
+    decode_state_value, hname, sort_accounts, AccountFoundriesResponse, AccountListResponse, AccountNftsResponse,
+    AccountNonceResponse, AccountsPage, AgentIdentity, Assets, AssetsDto, AssetsResponse, BlobInfoResponse,
+    BlobValueResponse, BlockInfo, ChainInfo, ChainSummary, CommitteeInfo, ErrorMessageFormatResponse, Event,
+    EventsResponse, JsonDict, NativeTokenIdRegistryResponse, NftData, NodeError, OffLedgerRequest, ReceiptResponse,
+    RequestIdsResponse, RequestMetadata, RequestProcessedResponse, Result, StateResponse, ToAgentIdString, VmError,
+    VmErrorCode, WaspInfo, ACCOUNTS,
+};
 
 /// Api (eventually) based on
 /// https://editor.swagger.io/?url=https://raw.githubusercontent.com/iotaledger/wasp/develop/clients/apiclient/api/openapi.yaml
 pub struct Api {
-    node: Node,
+    node_manager: NodeManager,
     http_client: HttpClient,
+    timeout: Duration,
+    /// Error message templates by chain and error code, they never change once registered.
+    error_message_formats: RwLock<HashMap<(String, VmErrorCode), String>>,
+    session: RwLock<Option<Session>>,
+    /// Held while the session is renewed, so concurrent requests log in only once.
+    session_refresh: tokio::sync::Mutex<()>,
+    cache: Option<ResponseCache>,
 }
 
 impl Api {
     pub fn new(url: Url) -> Self {
-        Self {
-            node: url.into(),
-            http_client: HttpClient::new("evm_sdk".to_string()),
+        Self::with_nodes([url]).expect("one node is given")
+    }
+
+    /// Creates an api that sends requests to several nodes. The first one is the primary node, it answers the routes
+    /// about a node itself, like the admin routes. Chain requests fail over to the next node if a node can't be
+    /// reached or answers with a server error.
+    /// See [`ApiBuilder`] to configure more than the nodes.
+    pub fn with_nodes(urls: impl IntoIterator<Item = Url>) -> Result<Self> {
+        ApiBuilder {
+            nodes: urls.into_iter().map(NodeDto::Url).collect(),
+            ..Default::default()
         }
+        .finish()
+    }
+
+    /// Returns a builder to configure the nodes, timeout, TLS and headers of an api.
+    pub fn builder() -> ApiBuilder {
+        ApiBuilder::new()
+    }
+
+    /// Sends requests that need quorum to `min_quorum_size` nodes and only accepts a response if at least
+    /// `quorum_threshold` percent of them returned it.
+    pub fn with_quorum(mut self, min_quorum_size: usize, quorum_threshold: usize) -> Self {
+        self.node_manager.set_quorum(min_quorum_size, quorum_threshold);
+        self
+    }
+
+    /// Caches the responses that never change, like receipts of processed requests and past blocks.
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sends the requests with a custom transport instead of reqwest. The TLS, proxy and timeout options of the
+    /// [`ApiBuilder`] are up to the transport then, the headers are still added to every request.
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.http_client.set_transport(std::sync::Arc::new(transport));
+        self
+    }
+
+    /// Adds hooks that are called before every request and after every response, for example for tracing or to add
+    /// headers. Hooks are called in the order they were added.
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.http_client.add_middleware(std::sync::Arc::new(middleware));
+        self
+    }
+
+    /// Returns how often the response cache could answer a request, `None` if there is no cache.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(ResponseCache::stats)
     }
 
     fn get_timeout(&self) -> Duration {
-        Duration::from_secs(10)
+        self.timeout
+    }
+
+    /// Connects to the websocket of this node to subscribe to the events of chains, with the auth of the node and
+    /// the JWT of the session if logged in.
+    /// GET /v1/ws
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn subscriber(&self) -> Result<crate::Subscriber> {
+        self.refresh_session().await;
+        let node = self.node_for(0, "v1/ws", None)?;
+        crate::Subscriber::connect(node.url, node.auth).await
     }
 
     /// Returns private information about this node.
@@ -47,6 +142,56 @@ impl Api {
         self.get_request(path, None, true, true).await
     }
 
+    /// Returns the chains hosted by this node and whether they are active.
+    /// GET /v1/chains
+    pub async fn chains(&self) -> Result<Vec<ChainSummary>> {
+        let path = "v1/chains";
+
+        self.get_request(path, None, true, true).await
+    }
+
+    /// Returns the information of a chain, including its fee policy and gas limits.
+    /// GET /v1/chains/{chainID}
+    pub async fn chain_info(&self, chain: &str) -> Result<ChainInfo> {
+        let path = &format!("v1/chains/{chain}");
+
+        self.get_request(path, None, true, true).await
+    }
+
+    /// Returns the committee and access nodes of a chain and whether they are alive and trusted.
+    /// GET /v1/chains/{chainID}/committee
+    pub async fn committee_info(&self, chain: &str) -> Result<CommitteeInfo> {
+        let path = &format!("v1/chains/{chain}/committee");
+
+        self.get_request(path, None, true, true).await
+    }
+
+    /// Calls a view function of a contract and returns the resulting dict. Its keys are bytes, they often aren't valid
+    /// UTF-8.
+    /// `block_index` queries the state at a past block instead of the latest one.
+    /// POST /v1/chains/{chainID}/callview
+    pub async fn call_view(
+        &self,
+        chain: &str,
+        contract_hname: u32,
+        function_hname: u32,
+        args: &HashMap<String, Vec<u8>>,
+        block_index: impl Into<Option<u32>>,
+    ) -> Result<HashMap<Vec<u8>, Vec<u8>>> {
+        let path = &format!("v1/chains/{chain}/callview");
+        let mut body = serde_json::json!({
+            "contractHName": format!("{contract_hname:08x}"),
+            "functionHName": format!("{function_hname:08x}"),
+            "arguments": JsonDict::from(args),
+        });
+        if let Some(block_index) = block_index.into() {
+            body["block"] = block_index.to_string().into();
+        }
+
+        let res: JsonDict = self.post_request(path, None, body, true, true, true).await?;
+        res.try_into()
+    }
+
     /// Returns the balance of an l1 address available for l2 transfers.
     /// GET /v1/chains/{chain}/core/accounts/account/{address}/balance
     pub async fn get_balance(&self, chain: &str, address: Bech32Address) -> Result<AssetsDto> {
@@ -55,6 +200,164 @@ impl Api {
         self.get_request(path, None, true, true).await
     }
 
+    /// Returns the nonce of an L2 account, needed for off-ledger requests and EVM transactions.
+    /// GET /v1/chains/{chainID}/core/accounts/account/{agentID}/nonce
+    pub async fn get_account_nonce<A: ToAgentIdString + ?Sized>(&self, chain: &str, agent_id: &A) -> Result<u64> {
+        let agent_id = agent_id.to_agent_id_string(chain);
+        let path = &format!("v1/chains/{chain}/core/accounts/account/{agent_id}/nonce");
+
+        let res: AccountNonceResponse = self.get_request(path, None, true, true).await?;
+        Ok(res.nonce)
+    }
+
+    /// Returns the IDs of the NFTs owned by an L2 account.
+    /// GET /v1/chains/{chainID}/core/accounts/account/{agentID}/nfts
+    pub async fn get_account_nfts<A: ToAgentIdString + ?Sized>(&self, chain: &str, agent_id: &A) -> Result<Vec<NftId>> {
+        let agent_id = agent_id.to_agent_id_string(chain);
+        let path = &format!("v1/chains/{chain}/core/accounts/account/{agent_id}/nfts");
+
+        let res: AccountNftsResponse = self.get_request(path, None, true, true).await?;
+        Ok(res.nft_ids)
+    }
+
+    /// Returns the serial numbers of the foundries owned by an L2 account.
+    /// GET /v1/chains/{chainID}/core/accounts/account/{agentID}/foundries
+    pub async fn get_account_foundries<A: ToAgentIdString + ?Sized>(
+        &self,
+        chain: &str,
+        agent_id: &A,
+    ) -> Result<Vec<u32>> {
+        let agent_id = agent_id.to_agent_id_string(chain);
+        let path = &format!("v1/chains/{chain}/core/accounts/account/{agent_id}/foundries");
+
+        let res: AccountFoundriesResponse = self.get_request(path, None, true, true).await?;
+        Ok(res.foundry_serial_numbers)
+    }
+
+    /// Returns the native tokens held by an L2 account.
+    /// GET /v1/chains/{chainID}/core/accounts/account/{agentID}/balance
+    pub async fn get_account_native_tokens<A: ToAgentIdString + ?Sized>(
+        &self,
+        chain: &str,
+        agent_id: &A,
+    ) -> Result<Vec<NativeToken>> {
+        let agent_id = agent_id.to_agent_id_string(chain);
+        let path = &format!("v1/chains/{chain}/core/accounts/account/{agent_id}/balance");
+
+        let res: AssetsResponse = self.get_request(path, None, true, true).await?;
+        let assets = Assets::try_from(res)?;
+        Ok(assets.get_native_tokens().cloned().unwrap_or_default())
+    }
+
+    /// Returns the data of an NFT held on the chain.
+    /// GET /v1/chains/{chainID}/core/accounts/nftdata/{nftID}
+    pub async fn get_nft_data(&self, chain: &str, nft_id: NftId) -> Result<NftData> {
+        let path = &format!("v1/chains/{chain}/core/accounts/nftdata/{nft_id}");
+
+        self.get_request(path, None, true, true).await
+    }
+
+    /// Returns the sum of the fungible tokens held by all L2 accounts of the chain.
+    /// GET /v1/chains/{chainID}/core/accounts/total_assets
+    pub async fn total_assets(&self, chain: &str) -> Result<Assets> {
+        let path = &format!("v1/chains/{chain}/core/accounts/total_assets");
+
+        let res: AssetsResponse = self.get_request(path, None, true, true).await?;
+        res.try_into()
+    }
+
+    /// Returns up to `page_size` owners of the L2 accounts of the chain, sorted by agent ID and starting at `offset`.
+    /// Wasp has no paging for this route, the pages are cut on the client: every page downloads the whole list
+    /// again, and accounts created between two calls can shift the later pages. Use
+    /// [`all_accounts`](Self::all_accounts) to get every account with one request.
+    /// GET /v1/chains/{chainID}/core/accounts
+    pub async fn accounts(&self, chain: &str, offset: usize, page_size: usize) -> Result<AccountsPage> {
+        let path = &format!("v1/chains/{chain}/core/accounts");
+
+        let res: AccountListResponse = self.get_request(path, None, true, true).await?;
+        Ok(AccountsPage::new(res.accounts, offset, page_size))
+    }
+
+    /// Returns the owners of all L2 accounts of the chain, sorted by agent ID like the pages of
+    /// [`accounts`](Self::accounts).
+    /// GET /v1/chains/{chainID}/core/accounts
+    pub async fn all_accounts(&self, chain: &str) -> Result<Vec<AgentIdentity>> {
+        let path = &format!("v1/chains/{chain}/core/accounts");
+
+        let res: AccountListResponse = self.get_request(path, None, true, true).await?;
+        let mut accounts = res.accounts;
+        sort_accounts(&mut accounts);
+        Ok(accounts)
+    }
+
+    /// Returns the IDs of all native tokens registered on the chain, see
+    /// [`get_account_native_tokens`](Self::get_account_native_tokens) for the tokens of an account.
+    /// GET /v1/chains/{chainID}/core/accounts/token_registry
+    pub async fn native_token_ids(&self, chain: &str) -> Result<Vec<TokenId>> {
+        let path = &format!("v1/chains/{chain}/core/accounts/token_registry");
+
+        let res: NativeTokenIdRegistryResponse = self.get_request(path, None, true, true).await?;
+        Ok(res.native_token_ids)
+    }
+
+    /// Returns the foundry output with the given serial number.
+    /// The REST route only returns the foundry ID, so this calls the `foundryOutput` view of the accounts contract.
+    pub async fn get_foundry_output(&self, chain: &str, serial_number: u32) -> Result<FoundryOutput> {
+        let mut args = HashMap::new();
+        args.insert("s".to_string(), serial_number.to_le_bytes().to_vec());
+
+        let res = self
+            .call_view(chain, hname(ACCOUNTS), hname("foundryOutput"), &args, None)
+            .await?;
+        let bytes = res.get(&b"b"[..]).ok_or(crate::Error::IO {
+            expected: std::io::ErrorKind::InvalidData,
+            message: "missing foundry output in view result",
+        })?;
+        match Output::unpack_unverified(bytes)? {
+            Output::Foundry(foundry) => Ok(foundry),
+            output => Err(crate::Error::UnexpectedOutputKind {
+                expected: "foundry",
+                found: output.kind(),
+            }),
+        }
+    }
+
+    /// Returns a raw value of the chain state, see [`state_key`](crate::state_key) to build keys.
+    /// GET /v1/chains/{chainID}/state/{stateKey}
+    pub async fn get_state_value(&self, chain: &str, key: &[u8]) -> Result<Vec<u8>> {
+        let path = &format!("v1/chains/{chain}/state/{}", prefix_hex::encode(key));
+
+        let res: StateResponse = self.get_request(path, None, true, true).await?;
+        Ok(res.state)
+    }
+
+    /// Returns a value of the chain state decoded as `T`.
+    pub async fn get_state_value_as<T>(&self, chain: &str, key: &[u8]) -> Result<T>
+    where
+        T: Packable,
+        T::UnpackError: Into<crate::Error>,
+    {
+        decode_state_value(self.get_state_value(chain, key).await?)
+    }
+
+    /// Returns the fields of a blob and their sizes in bytes.
+    /// GET /v1/chains/{chainID}/core/blobs/{blobHash}
+    pub async fn blob_info(&self, chain: &str, blob_hash: &[u8; 32]) -> Result<HashMap<String, u32>> {
+        let route = &format!("core/blobs/{}", prefix_hex::encode(blob_hash));
+
+        let res: BlobInfoResponse = self.get_immutable(chain, route).await?;
+        Ok(res.fields)
+    }
+
+    /// Returns the value of a field of a blob.
+    /// GET /v1/chains/{chainID}/core/blobs/{blobHash}/data/{fieldKey}
+    pub async fn blob_field(&self, chain: &str, blob_hash: &[u8; 32], field: &str) -> Result<Vec<u8>> {
+        let route = &format!("core/blobs/{}/data/{field}", prefix_hex::encode(blob_hash));
+
+        let res: BlobValueResponse = self.get_immutable(chain, route).await?;
+        Ok(res.value_data)
+    }
+
     /// Estimates gas for a given on-ledger ISC request
     /// POST /v1/chains/{chainID}/estimategas-onledger
     pub async fn estimate_gas_on_ledger(&self, chain: &str, json: Value) -> Result<ReceiptResponse> {
@@ -62,7 +365,30 @@ impl Api {
         let body = serde_json::json!({
             "outputBytes": json
         });
-        self.post_request(path, None, body, true, true).await
+        self.post_request(path, None, body, true, true, true).await
+    }
+
+    /// Estimates gas for an on-ledger ISC request carried by an output. The node simulates the request with an empty
+    /// output ID, so the output doesn't have to exist on the ledger yet.
+    /// POST /v1/chains/{chainID}/estimategas-onledger
+    pub async fn estimate_gas_for_output(&self, chain: &str, output: &Output) -> Result<ReceiptResponse> {
+        self.estimate_gas_on_ledger(chain, prefix_hex::encode(output.pack_to_vec()).into())
+            .await
+    }
+
+    /// Estimates gas for an on-ledger ISC request sent by `sender` with `amount` base tokens, see
+    /// [`RequestMetadata::to_output`].
+    /// POST /v1/chains/{chainID}/estimategas-onledger
+    pub async fn estimate_gas_for_request(
+        &self,
+        chain: &str,
+        metadata: &RequestMetadata,
+        sender: &Address,
+        amount: u64,
+    ) -> Result<ReceiptResponse> {
+        let output = metadata.to_output(&Bech32Address::try_from_str(chain)?, sender, amount)?;
+
+        self.estimate_gas_for_output(chain, &output).await
     }
 
     /// Estimates gas for a given off-ledger ISC request
@@ -73,54 +399,348 @@ impl Api {
             "requestBytes": prefix_hex::encode(metadata.pack_to_vec())
         });
 
-        self.post_request(path, None, body, true, true).await
+        self.post_request(path, None, body, true, true, true).await
     }
 
-    /// Get a receipt from a request ID
+    /// Submits a signed off-ledger request and returns its request ID. `chain` has to be the chain the request was
+    /// signed for, which is checked before anything is sent.
+    /// POST /v1/requests/offledger
+    pub async fn submit_off_ledger(&self, chain: &str, request: &OffLedgerRequest) -> Result<OutputId> {
+        if !request.is_signed() {
+            return Err(crate::Error::IO {
+                expected: std::io::ErrorKind::InvalidInput,
+                message: "the off-ledger request isn't signed",
+            });
+        }
+        if *Bech32Address::try_from_str(chain)?.inner() != Address::Alias(AliasAddress::new(*request.chain_id())) {
+            return Err(crate::Error::IO {
+                expected: std::io::ErrorKind::InvalidInput,
+                message: "the off-ledger request was signed for another chain",
+            });
+        }
+
+        let path = "v1/requests/offledger";
+        let body = serde_json::json!({
+            "chainId": chain,
+            "request": prefix_hex::encode(request.pack_to_vec())
+        });
+
+        // The node answers with 202 Accepted and an empty body
+        self.post_request_empty(path, None, body).await?;
+        request.id()
+    }
+
+    /// Waits until a request has been processed and returns its receipt.
+    /// Fails with [`Error::RequestTimeout`](crate::Error::RequestTimeout) if that didn't happen within `timeout`.
+    /// GET /v1/chains/{chainID}/requests/{requestID}/wait
+    pub async fn wait_for_request(
+        &self,
+        chain: &str,
+        request_id: OutputId,
+        timeout: Duration,
+    ) -> Result<ReceiptResponse> {
+        let path = &format!("v1/chains/{chain}/requests/{request_id}/wait");
+        let query = query_tuples_to_query_string([Some(("timeoutSeconds", timeout.as_secs().max(1).to_string()))]);
+
+        // Give the node time to answer after its own timeout passed
+        let res = self
+            .get_request_with_timeout(path, query.as_deref(), timeout + self.get_timeout())
+            .await;
+        match res {
+            Err(e) if is_timeout(e.last_attempt()) => Err(crate::Error::RequestTimeout { request_id, timeout }),
+            res => res,
+        }
+    }
+
+    /// Get a receipt from a request ID. Cached if the api has a [`ResponseCache`], a receipt only exists once the
+    /// request was processed.
     /// GET /v1/chains/{chainID}/receipts/{requestID}
     pub async fn get_receipt(&self, chain: &str, request_id: OutputId) -> Result<ReceiptResponse> {
-        let path = &format!("v1/chains/{chain}/receipts/{request_id}");
+        let route = &format!("receipts/{request_id}");
+
+        self.get_immutable(chain, route).await
+    }
+
+    /// Returns the message template of a VM error, as registered with the errors contract of the chain.
+    /// Templates are cached per chain.
+    /// GET /v1/chains/{chainID}/core/errors/{contractHname}/message/{errorID}
+    pub async fn error_message_format(&self, chain: &str, code: VmErrorCode) -> Result<String> {
+        let key = (chain.to_string(), code);
+        if let Some(format) = self.error_message_formats.read().unwrap().get(&key) {
+            return Ok(format.clone());
+        }
+
+        // Kept in the map above rather than the response cache, so they are cached without one too
+        let path = &format!(
+            "v1/chains/{chain}/core/errors/{:08x}/message/{}",
+            code.contract_hname, code.id
+        );
+        let res: ErrorMessageFormatResponse = self.get_request(path, None, true, true).await?;
+
+        self.error_message_formats
+            .write()
+            .unwrap()
+            .insert(key, res.message_format.clone());
+        Ok(res.message_format)
+    }
+
+    /// Resolves the message of a VM error sent by the node. Returns `None` if there is no error.
+    pub async fn resolve_vm_error(&self, chain: &str, error: &NodeError) -> Result<Option<VmError>> {
+        match error.error_code()? {
+            Some(code) => {
+                let format = self.error_message_format(chain, code).await?;
+                Ok(Some(VmError::new(code, error.params.clone(), &format)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Resolves the error of a receipt. Returns `None` if the request succeeded.
+    pub async fn receipt_error(&self, chain: &str, receipt: &ReceiptResponse) -> Result<Option<VmError>> {
+        self.resolve_vm_error(chain, &receipt.raw_error).await
+    }
+
+    /// Returns the latest block of a chain.
+    /// GET /v1/chains/{chainID}/core/blocklog/blocks/latest
+    pub async fn latest_block_info(&self, chain: &str) -> Result<BlockInfo> {
+        let path = &format!("v1/chains/{chain}/core/blocklog/blocks/latest");
 
         self.get_request(path, None, true, true).await
     }
 
+    /// Returns the block with the given index. Cached if the api has a [`ResponseCache`].
+    /// GET /v1/chains/{chainID}/core/blocklog/blocks/{blockIndex}
+    pub async fn block_info(&self, chain: &str, block_index: u32) -> Result<BlockInfo> {
+        let route = &format!("core/blocklog/blocks/{block_index}");
+
+        self.get_immutable(chain, route).await
+    }
+
+    /// Returns the IDs of the requests processed in a block.
+    /// GET /v1/chains/{chainID}/core/blocklog/blocks/{blockIndex}/requestids
+    pub async fn block_request_ids(&self, chain: &str, block_index: u32) -> Result<Vec<OutputId>> {
+        let route = &format!("core/blocklog/blocks/{block_index}/requestids");
+
+        let res: RequestIdsResponse = self.get_immutable(chain, route).await?;
+        Ok(res.request_ids)
+    }
+
+    /// Returns the receipts of the requests processed in a block.
+    /// GET /v1/chains/{chainID}/core/blocklog/blocks/{blockIndex}/receipts
+    pub async fn block_receipts(&self, chain: &str, block_index: u32) -> Result<Vec<ReceiptResponse>> {
+        let route = &format!("core/blocklog/blocks/{block_index}/receipts");
+
+        self.get_immutable(chain, route).await
+    }
+
+    /// Returns whether a request has been processed by the chain.
+    /// GET /v1/chains/{chainID}/core/blocklog/requests/{requestID}/is_processed
+    pub async fn is_request_processed(&self, chain: &str, request_id: OutputId) -> Result<bool> {
+        let path = &format!("v1/chains/{chain}/core/blocklog/requests/{request_id}/is_processed");
+
+        let res: RequestProcessedResponse = self.get_request(path, None, true, true).await?;
+        Ok(res.is_processed)
+    }
+
+    /// Returns the events emitted in a block.
+    /// GET /v1/chains/{chainID}/core/blocklog/events/block/{blockIndex}
+    pub async fn events_for_block(&self, chain: &str, block_index: u32) -> Result<Vec<Event>> {
+        let route = &format!("core/blocklog/events/block/{block_index}");
+
+        let res: EventsResponse = self.get_immutable(chain, route).await?;
+        Ok(res.events)
+    }
+
+    /// Returns the events emitted while processing a request.
+    /// GET /v1/chains/{chainID}/core/blocklog/events/request/{requestID}
+    pub async fn events_for_request(&self, chain: &str, request_id: OutputId) -> Result<Vec<Event>> {
+        let path = &format!("v1/chains/{chain}/core/blocklog/events/request/{request_id}");
+
+        let res: EventsResponse = self.get_request(path, None, true, true).await?;
+        Ok(res.events)
+    }
+
+    /// Returns the events emitted by a contract.
+    /// GET /v1/chains/{chainID}/core/blocklog/events/contract/{contractHname}
+    pub async fn events_for_contract(&self, chain: &str, contract_hname: u32) -> Result<Vec<Event>> {
+        let path = &format!("v1/chains/{chain}/core/blocklog/events/contract/{contract_hname:08x}");
+
+        let res: EventsResponse = self.get_request(path, None, true, true).await?;
+        Ok(res.events)
+    }
+
+    /// Posts a request and returns the response. Set `may_resend` for requests that don't change state, like a view
+    /// call or gas estimation. Others, like submitting an EVM transaction, are only retried and sent to the next node
+    /// if the retry policy allows retrying requests that change state: if the node accepted the first one, the resent
+    /// one fails although the request went through.
     pub(crate) async fn post_request<T: DeserializeOwned + Debug + Serialize>(
         &self,
         path: &str,
         query: Option<&str>,
         json: serde_json::Value,
-        _need_quorum: bool,
+        need_quorum: bool,
         _prefer_permanode: bool,
+        may_resend: bool,
     ) -> Result<T> {
-        let mut node = self.node.clone();
-        node.url.set_path(path);
-        node.url.set_query(query);
-        if let Some(auth) = &node.auth {
-            if let Some((name, password)) = &auth.basic_auth_name_pwd {
-                node.url
-                    .set_username(name)
-                    .map_err(|_| iota_sdk::client::Error::UrlAuth("username"))?;
-                node.url
-                    .set_password(Some(password))
-                    .map_err(|_| iota_sdk::client::Error::UrlAuth("password"))?;
-            }
-        }
+        let timeout = self.get_timeout();
 
-        let res = self.http_client.post_json(node, self.get_timeout(), json).await;
-        match res {
-            Ok(r) => r.into_json().await.map_err(|e| crate::Error::ClientError(e.into())),
-            Err(e) => Err(crate::Error::ClientError(e.into())),
+        if may_resend {
+            return self
+                .request(path, query, need_quorum, |node| {
+                    self.http_client.post_json(node, timeout, json.clone(), true)
+                })
+                .await;
         }
+        let nodes = self.authorized_nodes_for(path, query).await?;
+        let res = self
+            .node_manager
+            .send(nodes, self.http_client.may_resend(false), |node| {
+                self.http_client.post_json(node, timeout, json.clone(), false)
+            })
+            .await?;
+        res.into_json()
+    }
+
+    /// Posts a request for which the node doesn't return a body. It is only retried and sent to the next node if the
+    /// retry policy allows retrying requests that change state.
+    pub(crate) async fn post_request_empty(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        json: serde_json::Value,
+    ) -> Result<()> {
+        let timeout = self.get_timeout();
+        let nodes = self.authorized_nodes_for(path, query).await?;
+
+        self.node_manager
+            .send(nodes, self.http_client.may_resend(false), |node| {
+                self.http_client.post_json(node, timeout, json.clone(), false)
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Puts a request for which the node doesn't return a body.
+    pub(crate) async fn put_request_empty(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        json: serde_json::Value,
+    ) -> Result<()> {
+        let timeout = self.get_timeout();
+        let nodes = self.authorized_nodes_for(path, query).await?;
+
+        self.node_manager
+            .send(nodes, true, |node| {
+                self.http_client.put_json(node, timeout, json.clone())
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn delete_request(&self, path: &str, query: Option<&str>) -> Result<()> {
+        let timeout = self.get_timeout();
+        let nodes = self.authorized_nodes_for(path, query).await?;
+
+        self.node_manager
+            .send(nodes, true, |node| self.http_client.delete(node, timeout))
+            .await?;
+        Ok(())
     }
 
     pub(crate) async fn get_request<T: DeserializeOwned + Debug + Serialize>(
         &self,
         path: &str,
         query: Option<&str>,
-        _need_quorum: bool,
+        need_quorum: bool,
         _prefer_permanode: bool,
     ) -> Result<T> {
-        let mut node = self.node.clone();
+        let timeout = self.get_timeout();
+
+        self.request(path, query, need_quorum, |node| self.http_client.get(node, timeout))
+            .await
+    }
+
+    /// Gets a response of a chain that never changes once a node returned it, from the response cache if there is
+    /// one. The cache key combines the chain and `route`, the path below `v1/chains/{chain}/`.
+    async fn get_immutable<T: DeserializeOwned + Debug + Serialize>(&self, chain: &str, route: &str) -> Result<T> {
+        let path = &format!("v1/chains/{chain}/{route}");
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => return self.get_request(path, None, true, true).await,
+        };
+
+        let key = &format!("{chain}/{route}");
+        let value = match cache.get(key).await {
+            Some(value) => value,
+            None => {
+                let value: Value = self.get_request(path, None, true, true).await?;
+                cache.insert(key, &value).await;
+                value
+            }
+        };
+        Ok(serde_json::from_value(value)?)
+    }
+
+    pub(crate) async fn get_request_with_timeout<T: DeserializeOwned + Debug + Serialize>(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        timeout: Duration,
+    ) -> Result<T> {
+        self.request(path, query, false, |node| self.http_client.get(node, timeout))
+            .await
+    }
+
+    /// Sends a request to the nodes for `path` and parses the JSON response, comparing the responses of several
+    /// nodes if `need_quorum` is set and quorum is enabled.
+    async fn request<T, F, Fut>(&self, path: &str, query: Option<&str>, need_quorum: bool, send: F) -> Result<T>
+    where
+        T: DeserializeOwned,
+        F: Fn(Node) -> Fut,
+        Fut: Future<Output = Result<Response>>,
+    {
+        let nodes = self.authorized_nodes_for(path, query).await?;
+
+        if need_quorum && self.node_manager.quorum && !is_node_route(path) {
+            self.node_manager.send_with_quorum(nodes, send).await
+        } else {
+            let res = self.node_manager.send(nodes, true, send).await?;
+            res.into_json()
+        }
+    }
+
+    /// Like [`Api::nodes_for`], but renews the JWTs of the session first if they are about to expire.
+    async fn authorized_nodes_for(&self, path: &str, query: Option<&str>) -> Result<Vec<(usize, Node)>> {
+        self.refresh_session().await;
+        self.nodes_for(path, query)
+    }
+
+    /// Returns the nodes to send a request for `path` to, in the order they should be tried.
+    fn nodes_for(&self, path: &str, query: Option<&str>) -> Result<Vec<(usize, Node)>> {
+        self.node_manager
+            .nodes_for(path)
+            .into_iter()
+            .map(|index| Ok((index, self.node_for(index, path, query)?)))
+            .collect()
+    }
+
+    /// Returns a node with its url set to the given path and query, including basic auth if configured and the JWT
+    /// the node issued if logged in.
+    fn node_for(&self, index: usize, path: &str, query: Option<&str>) -> Result<Node> {
+        let mut node = self.node_manager.node(index).clone();
+        if let Some(jwt) = self
+            .session
+            .read()
+            .unwrap()
+            .as_ref()
+            .and_then(|session| session.jwts.get(&index))
+        {
+            node.auth = Some(NodeAuth {
+                jwt: Some(jwt.clone()),
+                basic_auth_name_pwd: node.auth.and_then(|auth| auth.basic_auth_name_pwd),
+            });
+        }
         node.url.set_path(path);
         node.url.set_query(query);
         if let Some(auth) = &node.auth {
@@ -133,12 +753,16 @@ impl Api {
                     .map_err(|_| iota_sdk::client::Error::UrlAuth("password"))?;
             }
         }
+        Ok(node)
+    }
+}
 
-        let res = self.http_client.get_bytes(node, self.get_timeout()).await;
-        match res {
-            Ok(r) => r.into_json().await.map_err(|e| crate::Error::ClientError(e.into())),
-            Err(e) => Err(crate::Error::ClientError(e.into())),
-        }
+/// Whether the node or the client timed out waiting.
+fn is_timeout(error: &crate::Error) -> bool {
+    match error {
+        crate::Error::ClientError(ClientError::Node(NodeApiError::ResponseError { code: 408, .. })) => true,
+        crate::Error::ClientError(ClientError::Node(NodeApiError::Reqwest(e))) => e.is_timeout(),
+        _ => false,
     }
 }
 
@@ -152,3 +776,54 @@ pub(crate) fn query_tuples_to_query_string(
 
     if query.is_empty() { None } else { Some(query.join("&")) }
 }
+
+#[cfg(test)]
+mod tests {
+    use iota_sdk::{
+        crypto::signatures::ed25519::SecretKey,
+        types::block::{
+            address::{AliasAddress, Bech32Address, Hrp},
+            output::AliasId,
+        },
+    };
+
+    use super::transport::fake::{api, response, FakeTransport};
+    use crate::OffLedgerRequest;
+
+    #[tokio::test]
+    async fn submit_off_ledger() {
+        let chain = Bech32Address::new(Hrp::from_str_unchecked("rms"), AliasAddress::new(AliasId::new([1; 32])));
+        let other_chain = Bech32Address::new(Hrp::from_str_unchecked("rms"), AliasAddress::new(AliasId::new([2; 32])));
+        let fake = FakeTransport::json(202, "");
+        let api = api(&["http://localhost:9090"], fake.clone());
+
+        let mut request = OffLedgerRequest::new(AliasId::new([1; 32]), None, None, 0, 10000);
+        assert!(api.submit_off_ledger(&chain.to_string(), &request).await.is_err());
+        request.sign(&SecretKey::from_bytes(&[3; 32]));
+        assert!(api.submit_off_ledger(&other_chain.to_string(), &request).await.is_err());
+        assert!(fake.requests().is_empty());
+
+        assert_eq!(
+            api.submit_off_ledger(&chain.to_string(), &request).await.unwrap(),
+            request.id().unwrap()
+        );
+        let body: serde_json::Value = serde_json::from_slice(fake.requests()[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["chainId"], chain.to_string());
+    }
+
+    #[tokio::test]
+    async fn all_accounts() {
+        let low = "0x0101010101010101010101010101010101010101";
+        let high = "0x0303030303030303030303030303030303030303";
+        let body = format!(r#"{{"accounts":["{high}","{low}"]}}"#);
+        let fake = FakeTransport::new(move |_, _| Ok(response(200, &body)));
+        let api = api(&["http://localhost:9090"], fake.clone());
+
+        let accounts = api.all_accounts("chain").await.unwrap();
+        assert_eq!(
+            accounts.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [low, high]
+        );
+        assert_eq!(fake.requests().len(), 1);
+    }
+}
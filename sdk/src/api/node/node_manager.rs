@@ -0,0 +1,278 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The node manager that takes care of sending requests to healthy nodes and comparing their responses for quorum
+
+use std::{collections::HashMap, future::Future, sync::RwLock};
+
+use instant::{Duration, Instant};
+use iota_sdk::client::{node_api::error::Error as NodeApiError, node_manager::node::Node, Error as ClientError};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::{http_client::Response, transport::is_connect_error};
+use crate::Result;
+
+/// How long a node that failed isn't preferred anymore.
+const UNHEALTHY_TIMEOUT: Duration = Duration::from_secs(30);
+pub(crate) const DEFAULT_MIN_QUORUM_SIZE: usize = 3;
+pub(crate) const DEFAULT_QUORUM_THRESHOLD: usize = 66;
+
+/// Routes about a node itself rather than a chain, they are always sent to the primary node.
+pub(crate) fn is_node_route(path: &str) -> bool {
+    path == "auth" || path.starts_with("v1/node") || path.starts_with("v1/users")
+}
+
+/// Whether an error means the node can't serve requests right now, so the next node should be tried.
+fn fails_over(error: &crate::Error) -> bool {
+    match error {
+        crate::Error::ClientError(ClientError::Node(NodeApiError::ResponseError { code, .. })) => *code >= 500,
+        crate::Error::RetriesExhausted { source, .. } => fails_over(source),
+        error => is_connect_error(error),
+    }
+}
+
+/// Keeps track of the nodes of an [`Api`](crate::Api). Chain requests go to the first healthy node and fail over to
+/// the next one on connection errors and 5xx responses, routes about a node itself always go to the first node.
+pub(crate) struct NodeManager {
+    nodes: Vec<Node>,
+    /// When the nodes that failed may be preferred again, by index.
+    unhealthy_until: RwLock<HashMap<usize, Instant>>,
+    pub(crate) quorum: bool,
+    min_quorum_size: usize,
+    quorum_threshold: usize,
+}
+
+impl NodeManager {
+    pub(crate) fn new(nodes: Vec<Node>) -> Result<Self> {
+        if nodes.is_empty() {
+            return Err(ClientError::HealthyNodePoolEmpty.into());
+        }
+        Ok(Self {
+            nodes,
+            unhealthy_until: Default::default(),
+            quorum: false,
+            min_quorum_size: DEFAULT_MIN_QUORUM_SIZE,
+            quorum_threshold: DEFAULT_QUORUM_THRESHOLD,
+        })
+    }
+
+    /// Requires `quorum_threshold` percent of `min_quorum_size` nodes to return the same response for requests that
+    /// need quorum.
+    pub(crate) fn set_quorum(&mut self, min_quorum_size: usize, quorum_threshold: usize) {
+        self.quorum = true;
+        self.min_quorum_size = min_quorum_size;
+        self.quorum_threshold = quorum_threshold;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub(crate) fn node(&self, index: usize) -> &Node {
+        &self.nodes[index]
+    }
+
+    /// Returns the indices of the nodes to send a request for `path` to, healthy nodes first in the configured order.
+    /// Unhealthy nodes are still tried last, they might have recovered.
+    pub(crate) fn nodes_for(&self, path: &str) -> Vec<usize> {
+        if is_node_route(path) {
+            return vec![0];
+        }
+        let now = Instant::now();
+        let unhealthy_until = self.unhealthy_until.read().unwrap();
+        let (mut healthy, unhealthy): (Vec<_>, Vec<_>) =
+            (0..self.nodes.len()).partition(|index| unhealthy_until.get(index).map_or(true, |until| *until <= now));
+        healthy.extend(unhealthy);
+        healthy
+    }
+
+    fn mark_unhealthy(&self, index: usize) {
+        log::warn!("node {} failed", self.nodes[index].url);
+        self.unhealthy_until
+            .write()
+            .unwrap()
+            .insert(index, Instant::now() + UNHEALTHY_TIMEOUT);
+    }
+
+    fn mark_healthy(&self, index: usize) {
+        self.unhealthy_until.write().unwrap().remove(&index);
+    }
+
+    /// Sends a request to the given nodes one after another until one of them answers. A request that may not be
+    /// sent again, like an off-ledger submission, is only sent to the first node, a node can fail after it accepted
+    /// the request.
+    pub(crate) async fn send<F, Fut>(&self, nodes: Vec<(usize, Node)>, resend: bool, send: F) -> Result<Response>
+    where
+        F: Fn(Node) -> Fut,
+        Fut: Future<Output = Result<Response>>,
+    {
+        let mut error = None;
+        for (index, node) in nodes {
+            match send(node).await {
+                Err(e) if fails_over(&e) => {
+                    self.mark_unhealthy(index);
+                    if !resend {
+                        return Err(e);
+                    }
+                    error.replace(e);
+                }
+                res => {
+                    self.mark_healthy(index);
+                    return res;
+                }
+            }
+        }
+        // There is at least one node, so either a response or an error was returned
+        Err(error.unwrap())
+    }
+
+    /// Sends a request to `min_quorum_size` of the given nodes at once and returns the response most of them agree on,
+    /// if that are at least `quorum_threshold` percent.
+    pub(crate) async fn send_with_quorum<T, F, Fut>(&self, nodes: Vec<(usize, Node)>, send: F) -> Result<T>
+    where
+        T: DeserializeOwned,
+        F: Fn(Node) -> Fut,
+        Fut: Future<Output = Result<Response>>,
+    {
+        if nodes.len() < self.min_quorum_size {
+            return Err(ClientError::QuorumPoolSizeError {
+                available_nodes: nodes.len(),
+                minimum_threshold: self.min_quorum_size,
+            }
+            .into());
+        }
+
+        let send = &send;
+        let responses = futures::future::join_all(
+            nodes
+                .into_iter()
+                .take(self.min_quorum_size)
+                .map(|(index, node)| async move { (index, send(node).await) }),
+        )
+        .await;
+
+        let mut results = HashMap::<String, usize>::new();
+        let mut error = None;
+        for (index, res) in responses {
+            match res {
+                Ok(res) => match res.into_json::<Value>() {
+                    Ok(value) => {
+                        self.mark_healthy(index);
+                        *results.entry(value.to_string()).or_default() += 1;
+                    }
+                    Err(e) => {
+                        error.replace(e);
+                    }
+                },
+                Err(e) => {
+                    if fails_over(&e) {
+                        self.mark_unhealthy(index);
+                    }
+                    error.replace(e);
+                }
+            }
+        }
+
+        let (res, count) = match results.into_iter().max_by_key(|(_, count)| *count) {
+            Some(res) => res,
+            None => return Err(error.unwrap()),
+        };
+        if count as f64 >= self.min_quorum_size as f64 * (self.quorum_threshold as f64 / 100.0) {
+            Ok(serde_json::from_str(&res)?)
+        } else {
+            Err(ClientError::QuorumThresholdError {
+                quorum_size: count,
+                minimum_threshold: self.min_quorum_size,
+            }
+            .into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use instant::Duration;
+    use serde_json::Value;
+
+    use super::super::transport::fake::{api, response, FakeTransport};
+    use crate::{ApiBuilder, Error, HttpRequest, HttpResponse, Result, RetryPolicy};
+
+    #[tokio::test]
+    async fn fails_over() {
+        let fake = FakeTransport::new(|request, _| match request.url.host_str() {
+            Some("node0") => Err(Error::Connect("fake".to_string())),
+            Some("node1") => Ok(response(500, "{}")),
+            _ => Ok(response(200, "[]")),
+        });
+        let api = api(&["http://node0", "http://node1", "http://node2"], fake);
+
+        assert!(api.chains().await.unwrap().is_empty());
+        assert_eq!(api.node_manager.nodes_for("v1/chains"), [2, 0, 1]);
+        assert_eq!(api.node_manager.nodes_for("v1/node/info"), [0]);
+
+        let fake = FakeTransport::new(|request, _| match request.url.host_str() {
+            Some("node0") => Ok(response(404, "{}")),
+            _ => Ok(response(200, "[]")),
+        });
+        let api = api(&["http://node0", "http://node1"], fake);
+        assert!(api.chains().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn state_changes_not_resent() {
+        fn respond(request: &HttpRequest, _: usize) -> Result<HttpResponse> {
+            match request.url.host_str() {
+                Some("node0") => Ok(response(504, "{}")),
+                _ => Ok(response(202, "")),
+            }
+        }
+        let nodes = ["http://node0", "http://node1"];
+
+        // The first node may have accepted the off-ledger request before it failed
+        let fake = FakeTransport::new(respond);
+        let api = api(&nodes, fake.clone());
+        assert!(api
+            .post_request_empty("v1/requests/offledger", None, serde_json::json!({}))
+            .await
+            .is_err());
+        assert_eq!(fake.requests().len(), 1);
+
+        let fake = FakeTransport::new(respond);
+        let api = ApiBuilder::new()
+            .with_nodes(&nodes)
+            .unwrap()
+            .with_retry_policy(RetryPolicy {
+                initial_delay: Duration::from_millis(1),
+                retry_non_idempotent: true,
+                ..Default::default()
+            })
+            .finish()
+            .unwrap()
+            .with_transport(fake.clone());
+        api.post_request_empty("v1/requests/offledger", None, serde_json::json!({}))
+            .await
+            .unwrap();
+        assert_eq!(fake.requests().last().unwrap().url.host_str(), Some("node1"));
+    }
+
+    #[tokio::test]
+    async fn quorum() {
+        let nodes = ["http://node0", "http://node1", "http://node2"];
+        let fake = FakeTransport::new(|request, _| match request.url.host_str() {
+            Some("node2") => Ok(response(200, r#"{"a":2}"#)),
+            _ => Ok(response(200, r#"{"a":1}"#)),
+        });
+
+        let api = api(&nodes, fake.clone()).with_quorum(3, 66);
+        let res: Value = api.get_request("v1/chains", None, true, false).await.unwrap();
+        assert_eq!(res, serde_json::json!({ "a": 1 }));
+
+        let api = api(&nodes, fake.clone()).with_quorum(3, 100);
+        assert!(api.get_request::<Value>("v1/chains", None, true, false).await.is_err());
+        assert!(api.get_request::<Value>("v1/chains", None, false, false).await.is_ok());
+
+        let api = api(&nodes, fake).with_quorum(4, 66);
+        assert!(api.get_request::<Value>("v1/chains", None, true, false).await.is_err());
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Retries of requests that failed for a reason that is likely temporary
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+use instant::Duration;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_INITIAL_DELAY: Duration = Duration::from_millis(500);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(10);
+
+/// How a request to a node is retried. Connection errors and 429, 502, 503 and 504 responses are retried with an
+/// exponential backoff with jitter, or after the delay the node asks for in its `Retry-After` header.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RetryPolicy {
+    /// How often a request is sent at most, including the first attempt. 1 disables retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry, it doubles with every further one
+    pub initial_delay: Duration,
+    /// The longest delay between two attempts. A request isn't retried if the node asks to wait longer.
+    pub max_delay: Duration,
+    /// If requests that change state, like the submission of an off-ledger request, are retried too. Such a request
+    /// is processed twice if the node received it but failed to answer.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_delay: DEFAULT_INITIAL_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends every request only once
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Returns how long to wait before the next attempt after `attempt` attempts failed, or `None` if the request
+    /// isn't retried anymore.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        match retry_after {
+            Some(retry_after) => (retry_after <= self.max_delay).then_some(retry_after),
+            None => {
+                let backoff = self
+                    .initial_delay
+                    .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+                    .min(self.max_delay);
+                Some(backoff.mul_f64(jitter()))
+            }
+        }
+    }
+}
+
+/// Whether a response status means the node can't serve the request right now, but likely can later.
+pub(crate) fn is_transient_status(status: u16) -> bool {
+    matches!(status, 429 | 502 | 503 | 504)
+}
+
+/// Reads the `Retry-After` header of a response. Only the delay in seconds is supported, not the HTTP date form.
+pub(crate) fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// A random factor between 0.5 and 1, so clients that failed at the same time don't retry at the same time.
+fn jitter() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    0.5 + (random >> 11) as f64 / (1u64 << 54) as f64
+}
+
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(target_family = "wasm")]
+    gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
+
+    #[cfg(not(target_family = "wasm"))]
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use instant::Duration;
+    use iota_sdk::types::block::{output::OutputId, payload::transaction::TransactionId};
+
+    use super::super::transport::fake::{api, FakeTransport};
+    use crate::{Error, RetryPolicy};
+
+    #[tokio::test]
+    async fn retries() {
+        let fake = FakeTransport::statuses(&[503, 429, 200]);
+        let api = api(&["http://localhost:9090"], fake.clone());
+        assert!(api.chains().await.unwrap().is_empty());
+        assert_eq!(fake.requests().len(), 3);
+
+        let fake = FakeTransport::statuses(&[502]);
+        let api = api(&["http://localhost:9090"], fake.clone());
+        assert!(matches!(
+            api.chains().await,
+            Err(Error::RetriesExhausted { attempts: 3, .. })
+        ));
+        assert_eq!(fake.requests().len(), 3);
+
+        let fake = FakeTransport::statuses(&[400, 200]);
+        let api = api(&["http://localhost:9090"], fake.clone());
+        assert!(matches!(api.chains().await, Err(Error::ClientError(_))));
+        assert_eq!(fake.requests().len(), 1);
+
+        // Off-ledger requests aren't submitted twice
+        let fake = FakeTransport::statuses(&[503, 200]);
+        let api = api(&["http://localhost:9090"], fake.clone());
+        assert!(api
+            .post_request_empty("v1/requests/offledger", None, serde_json::json!({}))
+            .await
+            .is_err());
+        assert_eq!(fake.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn last_error_not_retried() {
+        let fake = FakeTransport::statuses(&[503, 400]);
+        let api = api(&["http://localhost:9090"], fake.clone());
+        match api.chains().await {
+            Err(Error::RetriesExhausted { attempts: 2, source }) => assert!(matches!(*source, Error::ClientError(_))),
+            res => panic!("unexpected result {res:?}"),
+        }
+        assert_eq!(fake.requests().len(), 2);
+
+        // The node timed out waiting after it was unavailable first
+        let fake = FakeTransport::statuses(&[503, 408]);
+        let api = api(&["http://localhost:9090"], fake.clone());
+        let request_id = OutputId::new(TransactionId::new([0; 32]), 0).unwrap();
+        assert!(matches!(
+            api.wait_for_request("chain", request_id, Duration::from_secs(1)).await,
+            Err(Error::RequestTimeout { .. })
+        ));
+        assert_eq!(fake.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn delay() {
+        let policy = RetryPolicy::default();
+        for attempt in 1..3 {
+            let delay = policy.delay(attempt, None).unwrap();
+            let backoff = policy.initial_delay * 2u32.pow(attempt - 1);
+            assert!(delay >= backoff / 2 && delay < backoff);
+        }
+        assert_eq!(policy.delay(3, None), None);
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(2))),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(policy.delay(1, Some(Duration::from_secs(60))), None);
+        assert_eq!(RetryPolicy::disabled().delay(1, None), None);
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The HTTP transport the requests to nodes are sent with
+
+use std::time::Duration;
+
+use iota_sdk::client::{node_api::error::Error as NodeApiError, Error as ClientError};
+use reqwest::{header::HeaderMap, Method};
+use url::Url;
+
+use crate::{Error, Result};
+
+/// An HTTP request to a node.
+#[derive(Clone, Debug)]
+pub struct HttpRequest {
+    pub method: Method,
+    /// The url of the node with the path and query of the route. Contains the credentials if the node uses basic
+    /// auth.
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+    pub timeout: Duration,
+}
+
+/// An HTTP response of a node, with the whole body read.
+#[derive(Clone, Debug, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// The future a [`Transport`] returns.
+#[cfg(not(target_family = "wasm"))]
+pub type TransportFuture<'a> = futures::future::BoxFuture<'a, Result<HttpResponse>>;
+/// The future a [`Transport`] returns.
+#[cfg(target_family = "wasm")]
+pub type TransportFuture<'a> = futures::future::LocalBoxFuture<'a, Result<HttpResponse>>;
+
+/// Sends the HTTP requests of an [`Api`](crate::Api), see [`Api::with_transport`](crate::Api::with_transport).
+/// Implement it to send requests through a custom client, replay recorded responses or fake a node in tests.
+///
+/// A transport only sends a single request and returns whatever the node answered, the api adds the headers, retries,
+/// fails over to other nodes and parses the response. Return [`Error::Connect`] if the node can't be reached, so the
+/// request is retried and sent to the next node.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_>;
+}
+
+/// The default transport, which sends requests with a reqwest client.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let mut request_builder = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                request_builder = request_builder.body(body);
+            }
+            #[cfg(not(target_family = "wasm"))]
+            {
+                request_builder = request_builder.timeout(request.timeout);
+            }
+
+            let response = request_builder.send().await.map_err(reqwest_error)?;
+            Ok(HttpResponse {
+                status: response.status().as_u16(),
+                headers: response.headers().clone(),
+                body: response.bytes().await.map_err(reqwest_error)?.to_vec(),
+            })
+        })
+    }
+}
+
+pub(crate) fn reqwest_error(error: reqwest::Error) -> Error {
+    Error::ClientError(NodeApiError::from(error).into())
+}
+
+/// Whether an error means the node couldn't be reached.
+pub(crate) fn is_connect_error(error: &Error) -> bool {
+    match error {
+        Error::ClientError(ClientError::Node(NodeApiError::Reqwest(e))) => e.is_connect(),
+        Error::Connect(_) => true,
+        _ => false,
+    }
+}
+
+/// A transport that answers requests without the network, for the tests of the api.
+#[cfg(test)]
+pub(crate) mod fake {
+    use std::sync::{Arc, Mutex};
+
+    use instant::Duration;
+    use reqwest::header::{HeaderValue, RETRY_AFTER};
+
+    use super::{HttpRequest, HttpResponse, Transport, TransportFuture};
+    use crate::{Api, ApiBuilder, Error, Result, RetryPolicy};
+
+    type Respond = dyn Fn(&HttpRequest, usize) -> Result<HttpResponse> + Send + Sync;
+
+    /// Answers every request with a closure and records the requests.
+    #[derive(Clone)]
+    pub(crate) struct FakeTransport {
+        respond: Arc<Respond>,
+        requests: Arc<Mutex<Vec<HttpRequest>>>,
+    }
+
+    impl FakeTransport {
+        /// Answers with `respond`, which also gets how many requests were sent before.
+        pub(crate) fn new(
+            respond: impl Fn(&HttpRequest, usize) -> Result<HttpResponse> + Send + Sync + 'static,
+        ) -> Self {
+            Self {
+                respond: Arc::new(respond),
+                requests: Default::default(),
+            }
+        }
+
+        /// Answers every request with the same status and JSON body.
+        pub(crate) fn json(status: u16, body: &'static str) -> Self {
+            Self::new(move |_, _| Ok(response(status, body)))
+        }
+
+        /// Answers with the statuses one after another, repeating the last one. The body is an empty list and
+        /// `Retry-After` is 0.
+        pub(crate) fn statuses(statuses: &'static [u16]) -> Self {
+            Self::new(move |_, sent| {
+                let mut response = response(statuses[sent.min(statuses.len() - 1)], "[]");
+                response.headers.insert(RETRY_AFTER, HeaderValue::from_static("0"));
+                Ok(response)
+            })
+        }
+
+        /// Fails to connect for every request.
+        pub(crate) fn unreachable() -> Self {
+            Self::new(|_, _| Err(Error::Connect("fake".to_string())))
+        }
+
+        pub(crate) fn requests(&self) -> Vec<HttpRequest> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    impl Transport for FakeTransport {
+        fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+            let mut requests = self.requests.lock().unwrap();
+            let response = (self.respond)(&request, requests.len());
+            requests.push(request);
+            Box::pin(async move { response })
+        }
+    }
+
+    pub(crate) fn response(status: u16, body: &str) -> HttpResponse {
+        HttpResponse {
+            status,
+            body: body.as_bytes().to_vec(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns an api for the nodes that sends its requests with `transport` and retries right away.
+    pub(crate) fn api(nodes: &[&str], transport: FakeTransport) -> Api {
+        ApiBuilder::new()
+            .with_nodes(nodes)
+            .unwrap()
+            .with_user_agent("test")
+            .with_retry_policy(RetryPolicy {
+                initial_delay: Duration::from_millis(1),
+                ..Default::default()
+            })
+            .finish()
+            .unwrap()
+            .with_transport(transport)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::USER_AGENT;
+
+    use super::fake::{api, FakeTransport};
+    use crate::ChainSummary;
+
+    #[tokio::test]
+    async fn fake_transport() {
+        let fake = FakeTransport::json(200, r#"[{"chainID":"chain","isActive":true}]"#);
+        let api = api(&["http://localhost:9090"], fake.clone());
+
+        assert_eq!(
+            api.chains().await.unwrap(),
+            [ChainSummary {
+                chain_id: "chain".to_string(),
+                is_active: true,
+            }]
+        );
+        let requests = fake.requests();
+        assert_eq!(requests[0].url.as_str(), "http://localhost:9090/v1/chains");
+        assert_eq!(requests[0].headers[USER_AGENT], "test");
+    }
+
+    #[tokio::test]
+    async fn connect_error() {
+        let fake = FakeTransport::unreachable();
+        let api = api(&["http://localhost:9090", "http://localhost:9091"], fake.clone());
+
+        assert!(api.chains().await.is_err());
+        // Retried on both nodes
+        assert_eq!(fake.requests().len(), 6);
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use iota_sdk::{
+    types::block::{
+        address::Bech32Address,
+        output::{NativeToken, NftId, TokenId},
+    },
+    U256,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{AgentIdentity, Assets};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct AccountListResponse {
+    pub(crate) accounts: Vec<AgentIdentity>,
+}
+
+/// A page of the L2 accounts of a chain, see [`Api::accounts`](crate::Api::accounts). The node returns all accounts at
+/// once, pages are cut from them on the client.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsPage {
+    pub accounts: Vec<AgentIdentity>,
+    /// The offset of the next page, `None` if this is the last one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<usize>,
+    /// How many accounts the chain has.
+    pub total: usize,
+}
+
+impl AccountsPage {
+    /// Takes the page of `page_size` accounts starting at `offset` from all accounts, sorted by agent ID so the pages
+    /// don't depend on the order the node returned them in.
+    pub(crate) fn new(mut accounts: Vec<AgentIdentity>, offset: usize, page_size: usize) -> Self {
+        sort_accounts(&mut accounts);
+        let total = accounts.len();
+        let end = offset.saturating_add(page_size).min(total);
+        Self {
+            accounts: accounts.drain(offset.min(end)..end).collect(),
+            next_offset: (end < total).then_some(end),
+            total,
+        }
+    }
+}
+
+/// Sorts accounts by their agent ID.
+pub(crate) fn sort_accounts(accounts: &mut [AgentIdentity]) {
+    accounts.sort_by_cached_key(ToString::to_string);
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AssetsResponse {
+    #[serde(with = "iota_sdk::utils::serde::string")]
+    pub(crate) base_tokens: u64,
+    #[serde(default)]
+    pub(crate) native_tokens: Vec<NativeTokenResponse>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct NativeTokenResponse {
+    pub(crate) id: TokenId,
+    /// Decimal or 0x prefixed hex amount.
+    pub(crate) amount: String,
+}
+
+impl TryFrom<AssetsResponse> for Assets {
+    type Error = crate::Error;
+
+    fn try_from(res: AssetsResponse) -> crate::Result<Self> {
+        let mut assets = Assets::default();
+        assets.set_base_tokens(res.base_tokens);
+        for token in res.native_tokens {
+            let amount = match token.amount.strip_prefix("0x") {
+                Some(hex) => U256::from_str_radix(hex, 16).ok(),
+                None => U256::from_dec_str(&token.amount).ok(),
+            }
+            .ok_or(crate::Error::IO {
+                expected: std::io::ErrorKind::InvalidData,
+                message: "invalid native token amount",
+            })?;
+            assets.add_native_token(NativeToken::new(token.id, amount)?);
+        }
+        Ok(assets)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct AccountNonceResponse {
+    #[serde(with = "iota_sdk::utils::serde::string")]
+    pub(crate) nonce: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AccountNftsResponse {
+    pub(crate) nft_ids: Vec<NftId>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AccountFoundriesResponse {
+    pub(crate) foundry_serial_numbers: Vec<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct NativeTokenIdRegistryResponse {
+    #[serde(rename = "nativeTokenRegistryIds")]
+    pub(crate) native_token_ids: Vec<TokenId>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct BlobInfoResponse {
+    pub(crate) fields: HashMap<String, u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BlobValueResponse {
+    #[serde(with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+    pub(crate) value_data: Vec<u8>,
+}
+
+/// An NFT held on L2.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NftData {
+    pub id: NftId,
+    pub issuer: Bech32Address,
+    #[serde(with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+    pub metadata: Vec<u8>,
+    /// The agent ID of the L2 account owning the NFT.
+    pub owner: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_sdk::{types::block::output::NftId, U256};
+
+    use super::{
+        AccountFoundriesResponse, AccountNftsResponse, AccountNonceResponse, AccountsPage, AssetsResponse,
+        NativeTokenIdRegistryResponse, NftData,
+    };
+    use crate::{AgentIdentity, Assets, EvmAddress};
+
+    const TOKEN_ID: &str = "0x08abababababababababababababababababababababababababababababababab0100000000";
+    const NFT_ID: &str = "0xcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcd";
+
+    /// Parses response bodies in the format Wasp returns them.
+    #[tokio::test]
+    async fn deserialize() {
+        let res: AccountNonceResponse = serde_json::from_str(r#"{"nonce":"7"}"#).unwrap();
+        assert_eq!(res.nonce, 7);
+
+        let res: AccountNftsResponse = serde_json::from_str(&format!(r#"{{"nftIds":["{NFT_ID}"]}}"#)).unwrap();
+        assert_eq!(res.nft_ids, [NFT_ID.parse::<NftId>().unwrap()]);
+
+        let res: AccountFoundriesResponse = serde_json::from_str(r#"{"foundrySerialNumbers":[1,2]}"#).unwrap();
+        assert_eq!(res.foundry_serial_numbers, [1, 2]);
+
+        let res: NativeTokenIdRegistryResponse =
+            serde_json::from_str(&format!(r#"{{"nativeTokenRegistryIds":["{TOKEN_ID}"]}}"#)).unwrap();
+        assert_eq!(res.native_token_ids[0].to_string(), TOKEN_ID);
+
+        let res: AssetsResponse = serde_json::from_str(&format!(
+            r#"{{"baseTokens":"1000","nativeTokens":[{{"id":"{TOKEN_ID}","amount":"0x64"}}]}}"#
+        ))
+        .unwrap();
+        let assets = Assets::try_from(res).unwrap();
+        assert_eq!(assets.get_base_tokens(), 1000);
+        assert_eq!(assets.get_native_tokens().unwrap()[0].amount(), U256::from(100));
+
+        let nft: NftData = serde_json::from_str(&format!(
+            r#"{{"id":"{NFT_ID}","issuer":"rms1ppp00k5mmd2m8my8ukkp58nd3rskw6rx8l09aj35984k74uuc5u2cywn3ex",
+                "metadata":"0x0102","owner":"0xabababababababababababababababababababab"}}"#
+        ))
+        .unwrap();
+        assert_eq!(nft.metadata, [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn pages() {
+        let agent = |byte| AgentIdentity::Ethereum {
+            address: EvmAddress::from([byte; 20]),
+            chain: None,
+        };
+        let accounts = vec![agent(3), agent(1), agent(2)];
+
+        let page = AccountsPage::new(accounts.clone(), 0, 2);
+        assert_eq!(page.accounts, [agent(1), agent(2)]);
+        assert_eq!(page.next_offset, Some(2));
+        assert_eq!(page.total, 3);
+
+        let page = AccountsPage::new(accounts.clone(), 2, 2);
+        assert_eq!(page.accounts, [agent(3)]);
+        assert_eq!(page.next_offset, None);
+
+        assert!(AccountsPage::new(accounts, 5, 2).accounts.is_empty());
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct LoginResponse {
+    pub(crate) jwt: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct VersionResponse {
+    pub(crate) version: String,
+}
+
+/// The peering identity of a node.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeeringNodeIdentity {
+    pub is_trusted: bool,
+    pub name: String,
+    #[serde(rename = "peeringURL")]
+    pub peering_url: String,
+    /// Hex encoded public key of the peer.
+    pub public_key: String,
+}
+
+/// The status of a peer of a node.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeeringNodeStatus {
+    pub is_alive: bool,
+    pub is_trusted: bool,
+    pub name: String,
+    /// The number of chains using the peer.
+    pub num_users: u32,
+    #[serde(rename = "peeringURL")]
+    pub peering_url: String,
+    /// Hex encoded public key of the peer.
+    pub public_key: String,
+}
+
+/// A user of the node and its permissions.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    pub username: String,
+    pub permissions: Vec<String>,
+}
@@ -0,0 +1,253 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::PeeringNodeStatus;
+
+/// A chain hosted by a node, as listed by `GET /v1/chains`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainSummary {
+    #[serde(rename = "chainID")]
+    pub chain_id: String,
+    pub is_active: bool,
+}
+
+/// Describes a chain as reported by `GET /v1/chains/{chainID}`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainInfo {
+    #[serde(rename = "chainID")]
+    pub chain_id: String,
+    pub chain_owner_id: String,
+    pub evm_chain_id: u16,
+    pub gas_fee_policy: GasFeePolicy,
+    pub gas_limits: GasLimits,
+    pub is_active: bool,
+    pub metadata: ChainMetadata,
+    #[serde(rename = "publicURL")]
+    pub public_url: String,
+}
+
+impl ChainInfo {
+    /// Returns the fee in base tokens charged for the minimum amount of gas a request burns.
+    /// This is the lowest fee any request on this chain can be charged.
+    pub fn min_gas_fee(&self) -> u64 {
+        self.gas_fee_policy.fee_for_gas(self.gas_limits.min_gas_per_request)
+    }
+}
+
+/// The fee policy of a chain.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasFeePolicy {
+    /// How many gas units are paid for `b` base tokens.
+    pub gas_per_token: Ratio32,
+    /// The ratio between ISC gas and EVM gas.
+    pub evm_gas_ratio: Ratio32,
+    /// The percentage of the fees that is credited to the validators.
+    pub validator_fee_share: u8,
+}
+
+impl GasFeePolicy {
+    /// Calculates the fee in base tokens for the given amount of gas, rounding up like the chain does.
+    pub fn fee_for_gas(&self, gas: u64) -> u64 {
+        self.gas_per_token.y_ceil64(gas)
+    }
+}
+
+/// A ratio `a:b` as used by the chain parameters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Ratio32 {
+    pub a: u32,
+    pub b: u32,
+}
+
+impl Ratio32 {
+    /// Computes `ceil(x * b / a)`. Returns 0 when `a` is 0.
+    pub fn y_ceil64(&self, x: u64) -> u64 {
+        if self.a == 0 {
+            return 0;
+        }
+        let (a, b) = (self.a as u128, self.b as u128);
+        ((x as u128 * b + a - 1) / a) as u64
+    }
+}
+
+/// The gas limits of a chain.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasLimits {
+    pub max_gas_per_block: u64,
+    pub min_gas_per_request: u64,
+    pub max_gas_per_request: u64,
+    pub max_gas_external_view_call: u64,
+}
+
+/// The public metadata of a chain.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainMetadata {
+    #[serde(rename = "evmJsonRpcURL")]
+    pub evm_json_rpc_url: String,
+    #[serde(rename = "evmWebSocketURL")]
+    pub evm_web_socket_url: String,
+    pub name: String,
+    pub description: String,
+    pub website: String,
+}
+
+/// The committee and access nodes of a chain, as seen by the queried node.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitteeInfo {
+    pub chain_id: String,
+    pub active: bool,
+    /// The address controlling the state of the chain.
+    pub state_address: String,
+    pub committee_nodes: Vec<CommitteeNode>,
+    pub access_nodes: Vec<CommitteeNode>,
+    /// Nodes that offered to be access nodes but aren't accepted by the chain yet.
+    pub candidate_nodes: Vec<CommitteeNode>,
+}
+
+impl CommitteeInfo {
+    /// The number of committee nodes needed to agree on a block: all but the `(n - 1) / 3` nodes that may be faulty.
+    pub fn quorum(&self) -> usize {
+        let n = self.committee_nodes.len();
+        n - n.saturating_sub(1) / 3
+    }
+
+    /// Whether enough committee nodes are alive to produce blocks. `None` if the committee is unknown, access nodes
+    /// that aren't part of the committee return no committee nodes.
+    pub fn has_quorum(&self) -> Option<bool> {
+        if self.committee_nodes.is_empty() {
+            return None;
+        }
+        Some(self.committee_nodes.iter().filter(|node| node.node.is_alive).count() >= self.quorum())
+    }
+
+    /// The access nodes that are alive and trusted by the queried node.
+    pub fn healthy_access_nodes(&self) -> impl Iterator<Item = &CommitteeNode> + '_ {
+        self.access_nodes
+            .iter()
+            .filter(|node| node.node.is_alive && node.node.is_trusted)
+    }
+}
+
+/// A node of a chain committee or one of its access nodes.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CommitteeNode {
+    /// The url of the web API of the node.
+    #[serde(rename = "accessAPI")]
+    pub access_api: String,
+    pub node: PeeringNodeStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChainInfo, ChainSummary, CommitteeInfo, MIN_GAS_FEE};
+
+    const CHAIN_INFO: &str = r#"{
+        "chainID": "rms1ppp00k5mmd2m8my8ukkp58nd3rskw6rx8l09aj35984k74uuc5u2cywn3ex",
+        "chainOwnerId": "0x0000000000000000000000000000000000000000000000000000000000000000",
+        "evmChainId": 1073,
+        "gasFeePolicy": {
+            "gasPerToken": { "a": 100, "b": 1 },
+            "evmGasRatio": { "a": 1, "b": 1 },
+            "validatorFeeShare": 0
+        },
+        "gasLimits": {
+            "maxGasPerBlock": 1000000000,
+            "minGasPerRequest": 10000,
+            "maxGasPerRequest": 50000000,
+            "maxGasExternalViewCall": 50000000
+        },
+        "isActive": true,
+        "metadata": {
+            "evmJsonRpcURL": "https://json-rpc.evm.testnet.shimmer.network",
+            "evmWebSocketURL": "wss://ws.json-rpc.evm.testnet.shimmer.network",
+            "name": "ShimmerEVM Testnet",
+            "description": "",
+            "website": ""
+        },
+        "publicURL": "https://api.evm.testnet.shimmer.network"
+    }"#;
+
+    #[tokio::test]
+    async fn deserialize() {
+        let info = serde_json::from_str::<ChainInfo>(CHAIN_INFO).unwrap();
+        assert_eq!(info.evm_chain_id, 1073);
+        assert_eq!(info.gas_limits.min_gas_per_request, 10000);
+        assert_eq!(info.metadata.name, "ShimmerEVM Testnet");
+    }
+
+    #[tokio::test]
+    async fn summary() {
+        let chains = serde_json::from_str::<Vec<ChainSummary>>(&format!("[{CHAIN_INFO}]")).unwrap();
+        assert_eq!(
+            chains,
+            vec![ChainSummary {
+                chain_id: "rms1ppp00k5mmd2m8my8ukkp58nd3rskw6rx8l09aj35984k74uuc5u2cywn3ex".to_string(),
+                is_active: true,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn min_gas_fee() {
+        let info = serde_json::from_str::<ChainInfo>(CHAIN_INFO).unwrap();
+        assert_eq!(info.min_gas_fee(), MIN_GAS_FEE);
+        assert_eq!(info.gas_fee_policy.fee_for_gas(10001), 101);
+    }
+
+    fn committee_node(name: &str, is_alive: bool) -> serde_json::Value {
+        serde_json::json!({
+            "accessAPI": format!("http://{name}:9090"),
+            "node": {
+                "isAlive": is_alive,
+                "isTrusted": true,
+                "name": name,
+                "numUsers": 1,
+                "peeringURL": format!("{name}:4000"),
+                "publicKey": "0x0c8bc9cab4a2d0cd8fa2b4e3f2c61e3d1b1fe7bd0bd9e5d3b9c2a0f0f1e0d0c0"
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn committee_quorum() {
+        let mut committee = serde_json::from_value::<CommitteeInfo>(serde_json::json!({
+            "chainId": "rms1ppp00k5mmd2m8my8ukkp58nd3rskw6rx8l09aj35984k74uuc5u2cywn3ex",
+            "active": true,
+            "stateAddress": "rms1qp8rknypruss89dkqnnuedm87y7xmnmdj2tk3rrpcy3sw3ev52q0vzl42tr",
+            "committeeNodes": [
+                committee_node("a", true),
+                committee_node("b", true),
+                committee_node("c", true),
+                committee_node("d", false)
+            ],
+            "accessNodes": [committee_node("e", true), committee_node("f", false)],
+            "candidateNodes": []
+        }))
+        .unwrap();
+
+        assert_eq!(committee.quorum(), 3);
+        assert_eq!(committee.has_quorum(), Some(true));
+        assert_eq!(
+            committee
+                .healthy_access_nodes()
+                .map(|node| node.node.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["e"]
+        );
+
+        committee.committee_nodes[0].node.is_alive = false;
+        assert_eq!(committee.has_quorum(), Some(false));
+
+        // What an access node outside of the committee returns
+        committee.committee_nodes.clear();
+        assert_eq!(committee.has_quorum(), None);
+    }
+}
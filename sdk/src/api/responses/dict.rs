@@ -0,0 +1,83 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// A key/value dictionary as used by the Wasp API for view call arguments and results.
+/// Keys and values are hex encoded.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct JsonDict {
+    #[serde(rename = "Items", default)]
+    pub items: Vec<JsonDictItem>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct JsonDictItem {
+    pub key: String,
+    pub value: String,
+}
+
+impl From<&HashMap<String, Vec<u8>>> for JsonDict {
+    fn from(dict: &HashMap<String, Vec<u8>>) -> Self {
+        Self {
+            items: dict
+                .iter()
+                .map(|(key, value)| JsonDictItem {
+                    key: prefix_hex::encode(key.as_bytes()),
+                    value: prefix_hex::encode(value),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Keys are kept as bytes, as the keys of array and map elements or keys with an hname or address prefix aren't
+/// valid UTF-8.
+impl TryFrom<JsonDict> for HashMap<Vec<u8>, Vec<u8>> {
+    type Error = Error;
+
+    fn try_from(dict: JsonDict) -> Result<Self, Self::Error> {
+        dict.items
+            .into_iter()
+            .map(|item| Ok((prefix_hex::decode(&item.key)?, prefix_hex::decode(&item.value)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::JsonDict;
+
+    #[tokio::test]
+    async fn roundtrip() {
+        let mut dict = HashMap::new();
+        dict.insert("a".to_string(), vec![1, 2, 3]);
+
+        let json = JsonDict::from(&dict);
+        assert_eq!(
+            serde_json::to_value(&json).unwrap(),
+            serde_json::json!({ "Items": [{ "key": "0x61", "value": "0x010203" }] })
+        );
+        let res = HashMap::<Vec<u8>, Vec<u8>>::try_from(json).unwrap();
+        assert_eq!(res[&b"a"[..]], [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn binary_keys() {
+        // The length of an array and its first element
+        let json: JsonDict = serde_json::from_value(serde_json::json!({ "Items": [
+            { "key": "0x61", "value": "0x01000000" },
+            { "key": "0x612300000000", "value": "0xff" },
+        ] }))
+        .unwrap();
+
+        let res = HashMap::<Vec<u8>, Vec<u8>>::try_from(json).unwrap();
+        assert_eq!(res[&[0x61, 0x23, 0, 0, 0, 0][..]], [0xff]);
+    }
+}
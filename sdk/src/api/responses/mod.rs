@@ -1,19 +1,29 @@
 // Copyright 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod accounts;
+mod admin;
+mod chain;
+mod dict;
+mod vm_error;
+
 use std::collections::HashMap;
 
 use iota_sdk::types::block::output::OutputId;
 use serde::{Deserialize, Serialize};
 
-use crate::AssetsDto;
+pub use self::{accounts::*, admin::*, chain::*, dict::*, vm_error::*};
+use crate::{AssetsDto, Result};
 
 /// Describes a receipt.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReceiptResponse {
     pub request: Request,
+    /// Empty if the request succeeded.
+    #[serde(default)]
     pub raw_error: NodeError,
+    #[serde(default)]
     pub error_message: String,
     pub gas_budget: String,
     pub gas_burned: String,
@@ -24,6 +34,59 @@ pub struct ReceiptResponse {
     pub gas_burn_log: Vec<GasBurned>,
 }
 
+/// Describes a block of a chain.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockInfo {
+    pub block_index: u32,
+    /// RFC 3339 formatted time of the block.
+    pub timestamp: String,
+    pub total_requests: u16,
+    pub num_successful_requests: u16,
+    pub num_off_ledger_requests: u16,
+    pub previous_alias_output: String,
+    #[serde(with = "iota_sdk::utils::serde::string")]
+    pub gas_burned: u64,
+    #[serde(with = "iota_sdk::utils::serde::string")]
+    pub gas_fee_charged: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RequestIdsResponse {
+    pub(crate) request_ids: Vec<OutputId>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RequestProcessedResponse {
+    pub(crate) is_processed: bool,
+}
+
+/// An event emitted by a contract while processing a request.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    /// The hname of the contract that emitted the event.
+    #[serde(rename = "contractID")]
+    pub contract_hname: u32,
+    pub topic: String,
+    /// Unix timestamp of the event in nanoseconds.
+    pub timestamp: u64,
+    #[serde(with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+    pub payload: Vec<u8>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct EventsResponse {
+    pub(crate) events: Vec<Event>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct StateResponse {
+    #[serde(with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+    pub(crate) state: Vec<u8>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Request {
@@ -50,11 +113,23 @@ pub struct Target {
     function_hname: String,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+/// An error of the VM as sent by the node, see [`Api::resolve_vm_error`](crate::Api::resolve_vm_error).
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NodeError {
-    code: String,
-    params: Vec<String>,
+    pub code: String,
+    #[serde(default)]
+    pub params: Vec<String>,
+}
+
+impl NodeError {
+    /// Parses the error code, returns `None` if there is no error.
+    pub fn error_code(&self) -> Result<Option<VmErrorCode>> {
+        if self.code.is_empty() {
+            return Ok(None);
+        }
+        self.code.parse().map(Some)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -65,10 +140,12 @@ pub struct GasBurned {
 }
 
 #[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct WaspInfo {
+    #[serde(alias = "peeringURL")]
     peering_url: String,
     l1_params: L1Params,
-    public_key: f64,
+    public_key: String,
     version: String,
 }
 
@@ -0,0 +1,126 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// Identifies an error of the VM: the hname of the contract that defines it and its ID within that contract.
+/// Written as `{contractHname:08x}:{id}` by Wasp.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VmErrorCode {
+    pub contract_hname: u32,
+    pub id: u16,
+}
+
+impl VmErrorCode {
+    pub fn new(contract_hname: u32, id: u16) -> Self {
+        Self { contract_hname, id }
+    }
+}
+
+impl FromStr for VmErrorCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidVmErrorCode(s.to_string());
+        let (contract_hname, id) = s.split_once(':').ok_or_else(invalid)?;
+        Ok(Self {
+            contract_hname: u32::from_str_radix(contract_hname, 16).map_err(|_| invalid())?,
+            id: id.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl fmt::Display for VmErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:08x}:{}", self.contract_hname, self.id)
+    }
+}
+
+/// An error of the VM with its message resolved from the errors contract of the chain.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VmError {
+    pub code: VmErrorCode,
+    pub params: Vec<String>,
+    /// The message template of the error formatted with its params.
+    pub message: String,
+}
+
+impl VmError {
+    pub fn new(code: VmErrorCode, params: Vec<String>, message_format: &str) -> Self {
+        let message = format_message(message_format, &params);
+        Self { code, params, message }
+    }
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for VmError {}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ErrorMessageFormatResponse {
+    pub(crate) message_format: String,
+}
+
+/// Fills the Go format verbs of a message template with the params, which Wasp already sends as strings.
+/// Flags and widths are dropped, missing params are marked like Go does.
+fn format_message(template: &str, params: &[String]) -> String {
+    let mut message = String::with_capacity(template.len());
+    let mut params = params.iter();
+    let mut chars = template.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            message.push(c);
+            continue;
+        }
+        // Skip flags, width and precision up to the verb
+        let verb = chars
+            .by_ref()
+            .find(|c| !matches!(c, '+' | '-' | '#' | ' ' | '.' | '0'..='9'));
+        match verb {
+            Some('%') => message.push('%'),
+            Some(verb) => match params.next() {
+                Some(param) => message.push_str(param),
+                None => message.push_str(&format!("%!{verb}(MISSING)")),
+            },
+            None => message.push_str("%!(NOVERB)"),
+        }
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_message;
+    use crate::{hname, VmError, VmErrorCode, ACCOUNTS};
+
+    #[tokio::test]
+    async fn parse_code() {
+        let code = "3c4b5e02:1".parse::<VmErrorCode>().unwrap();
+        assert_eq!(code, VmErrorCode::new(hname(ACCOUNTS), 1));
+        assert_eq!(code.to_string(), "3c4b5e02:1");
+        assert!("3c4b5e02".parse::<VmErrorCode>().is_err());
+        assert!("xyz:1".parse::<VmErrorCode>().is_err());
+    }
+
+    #[tokio::test]
+    async fn format() {
+        let error = VmError::new(
+            VmErrorCode::new(0, 1),
+            vec!["10".to_string(), "foo".to_string()],
+            "gas budget %d exceeded in %s: 100%%",
+        );
+        assert_eq!(error.to_string(), "gas budget 10 exceeded in foo: 100%");
+        assert_eq!(format_message("%v and %05d", &["a".to_string()]), "a and %!d(MISSING)");
+    }
+}
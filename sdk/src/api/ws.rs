@@ -0,0 +1,501 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Subscriptions to the events a node publishes on its websocket
+
+use std::{
+    collections::{HashMap, HashSet},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use futures::{SinkExt, Stream, StreamExt};
+use instant::Duration;
+use iota_sdk::client::node_manager::node::NodeAuth;
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::{net::TcpStream, sync::mpsc};
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{
+        client::IntoClientRequest,
+        handshake::client::Request,
+        http::{header::AUTHORIZATION, HeaderMap, HeaderValue},
+        Message,
+    },
+    MaybeTlsStream, WebSocketStream,
+};
+use url::Url;
+
+use crate::{BlockInfo, Error, Event, ReceiptResponse, Result};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// The delay before the first reconnect attempt, doubled after each failed attempt.
+const RECONNECT_DELAY: Duration = Duration::from_millis(500);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+/// How many received messages are buffered until the subscriber reads them.
+const MESSAGE_BUFFER: usize = 1024;
+
+/// The kinds of events a node publishes for a chain.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Topic {
+    /// A block was applied to the chain.
+    NewBlock,
+    /// A request was processed.
+    Receipt,
+    /// The contract events emitted in a block.
+    BlockEvents,
+}
+
+impl Topic {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Topic::NewBlock => "new_block",
+            Topic::Receipt => "receipt",
+            Topic::BlockEvents => "block_events",
+        }
+    }
+}
+
+/// A message received on a subscription.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SubscriptionMessage {
+    NewBlock { chain_id: String, block: BlockInfo },
+    Receipt { chain_id: String, receipt: ReceiptResponse },
+    BlockEvents { chain_id: String, events: Vec<Event> },
+}
+
+impl SubscriptionMessage {
+    pub fn chain_id(&self) -> &str {
+        match self {
+            Self::NewBlock { chain_id, .. } | Self::Receipt { chain_id, .. } | Self::BlockEvents { chain_id, .. } => {
+                chain_id
+            }
+        }
+    }
+
+    pub fn topic(&self) -> Topic {
+        match self {
+            Self::NewBlock { .. } => Topic::NewBlock,
+            Self::Receipt { .. } => Topic::Receipt,
+            Self::BlockEvents { .. } => Topic::BlockEvents,
+        }
+    }
+}
+
+/// An event as published by the node.
+#[derive(Debug, Deserialize)]
+struct IscEvent {
+    kind: String,
+    #[serde(rename = "chainID")]
+    chain_id: String,
+    payload: Value,
+}
+
+enum Command {
+    Subscribe(String, Topic),
+    Unsubscribe(String, Topic),
+}
+
+/// Chains and the topics subscribed to for each of them.
+#[derive(Default)]
+struct Subscriptions(HashMap<String, HashSet<Topic>>);
+
+impl Subscriptions {
+    fn contains(&self, chain_id: &str, topic: Topic) -> bool {
+        self.0.get(chain_id).map_or(false, |topics| topics.contains(&topic))
+    }
+
+    fn has_topic(&self, topic: Topic) -> bool {
+        self.0.values().any(|topics| topics.contains(&topic))
+    }
+
+    /// Applies a command and returns the topics the node has to be (un)subscribed from.
+    /// The node subscribes chains and kinds of events separately, so both are sent as topics and messages are
+    /// filtered again when they are received.
+    fn apply(&mut self, command: Command) -> Vec<Message> {
+        let mut frames = Vec::new();
+        match command {
+            Command::Subscribe(chain_id, topic) => {
+                if !self.has_topic(topic) {
+                    frames.push(frame("subscribe", topic.as_str()));
+                }
+                let topics = self.0.entry(chain_id.clone()).or_default();
+                if topics.is_empty() {
+                    frames.push(frame("subscribe", &chain_id));
+                }
+                topics.insert(topic);
+            }
+            Command::Unsubscribe(chain_id, topic) => {
+                let removed = match self.0.get_mut(&chain_id) {
+                    Some(topics) => topics.remove(&topic),
+                    None => false,
+                };
+                if removed {
+                    if self.0[&chain_id].is_empty() {
+                        self.0.remove(&chain_id);
+                        frames.push(frame("unsubscribe", &chain_id));
+                    }
+                    if !self.has_topic(topic) {
+                        frames.push(frame("unsubscribe", topic.as_str()));
+                    }
+                }
+            }
+        }
+        frames
+    }
+
+    /// The frames to restore all subscriptions on a new connection.
+    fn frames(&self) -> Vec<Message> {
+        let topics = self.0.values().flatten().collect::<HashSet<_>>();
+        topics
+            .into_iter()
+            .map(|topic| frame("subscribe", topic.as_str()))
+            .chain(self.0.keys().map(|chain_id| frame("subscribe", chain_id)))
+            .collect()
+    }
+
+    /// Parses a message of the node, returns `None` if it is of no subscribed topic.
+    fn parse(&self, text: &str) -> Option<Result<SubscriptionMessage>> {
+        let event = match serde_json::from_str::<IscEvent>(text) {
+            Ok(event) => event,
+            Err(e) => return Some(Err(e.into())),
+        };
+        let topic = match event.kind.as_str() {
+            "new_block" => Topic::NewBlock,
+            "receipt" => Topic::Receipt,
+            "block_events" => Topic::BlockEvents,
+            _ => return None,
+        };
+        if !self.contains(&event.chain_id, topic) {
+            return None;
+        }
+
+        let chain_id = event.chain_id;
+        let message = match topic {
+            Topic::NewBlock => {
+                serde_json::from_value(event.payload).map(|block| SubscriptionMessage::NewBlock { chain_id, block })
+            }
+            Topic::Receipt => {
+                serde_json::from_value(event.payload).map(|receipt| SubscriptionMessage::Receipt { chain_id, receipt })
+            }
+            Topic::BlockEvents => serde_json::from_value(event.payload)
+                .map(|events| SubscriptionMessage::BlockEvents { chain_id, events }),
+        };
+        Some(message.map_err(Into::into))
+    }
+}
+
+fn frame(command: &str, topic: &str) -> Message {
+    Message::Text(serde_json::json!({ "command": command, "topic": topic }).to_string())
+}
+
+/// Receives the events a node publishes for the subscribed chains as a [`Stream`].
+///
+/// The connection is kept in a background task which reconnects when it drops and restores the subscriptions. It is
+/// closed when the subscriber is dropped.
+///
+/// Up to 1024 received messages are buffered. Once the buffer is full, the task stops reading from the socket until
+/// the subscriber catches up, so no message is dropped but the node may close the connection of a subscriber that
+/// falls too far behind. The connection is then reestablished, events published in between are missed.
+pub struct Subscriber {
+    commands: mpsc::UnboundedSender<Command>,
+    messages: mpsc::Receiver<Result<SubscriptionMessage>>,
+}
+
+impl Subscriber {
+    /// Connects to the websocket of the node with the given API url. The auth is sent like for HTTP requests: basic
+    /// auth, unless the node issued a JWT. Reconnects use the same auth.
+    pub async fn connect(url: Url, auth: Option<NodeAuth>) -> Result<Self> {
+        let url = ws_url(url)?;
+        let headers = auth_headers(auth.as_ref())?;
+        let (socket, _) = connect_async(request(&url, &headers)?).await?;
+
+        let (commands, commands_rx) = mpsc::unbounded_channel();
+        let (messages_tx, messages) = mpsc::channel(MESSAGE_BUFFER);
+        tokio::spawn(run(url, headers, socket, commands_rx, messages_tx));
+
+        Ok(Self { commands, messages })
+    }
+
+    /// Subscribes to topics of a chain.
+    pub fn subscribe(&self, chain: &str, topics: &[Topic]) {
+        for topic in topics {
+            // The task only stops once the subscriber is dropped
+            self.commands.send(Command::Subscribe(chain.to_string(), *topic)).ok();
+        }
+    }
+
+    /// Unsubscribes from topics of a chain.
+    pub fn unsubscribe(&self, chain: &str, topics: &[Topic]) {
+        for topic in topics {
+            self.commands.send(Command::Unsubscribe(chain.to_string(), *topic)).ok();
+        }
+    }
+}
+
+impl Stream for Subscriber {
+    type Item = Result<SubscriptionMessage>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.messages.poll_recv(cx)
+    }
+}
+
+/// Returns the websocket url of a node from its API url. Credentials are sent in a header instead.
+fn ws_url(mut url: Url) -> Result<Url> {
+    let scheme = if url.scheme() == "https" { "wss" } else { "ws" };
+    url.set_scheme(scheme)
+        .map_err(|_| iota_sdk::client::Error::UrlValidation(format!("invalid websocket url {url}")))?;
+    url.set_path("v1/ws");
+    url.set_username("").ok();
+    url.set_password(None).ok();
+    Ok(url)
+}
+
+/// Returns the authorization header for the node auth. A JWT takes precedence over basic auth, like for HTTP requests.
+fn auth_headers(auth: Option<&NodeAuth>) -> Result<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    let value = match auth {
+        Some(NodeAuth { jwt: Some(jwt), .. }) => format!("Bearer {jwt}"),
+        Some(NodeAuth {
+            basic_auth_name_pwd: Some((name, password)),
+            ..
+        }) => format!("Basic {}", STANDARD.encode(format!("{name}:{password}"))),
+        _ => return Ok(headers),
+    };
+    headers.insert(
+        AUTHORIZATION,
+        HeaderValue::from_str(&value).map_err(|_| Error::InvalidHeader(AUTHORIZATION.to_string()))?,
+    );
+    Ok(headers)
+}
+
+fn request(url: &Url, headers: &HeaderMap) -> Result<Request> {
+    let mut request = url.as_str().into_client_request()?;
+    request.headers_mut().extend(headers.clone());
+    Ok(request)
+}
+
+async fn run(
+    url: Url,
+    headers: HeaderMap,
+    mut socket: Socket,
+    mut commands: mpsc::UnboundedReceiver<Command>,
+    messages: mpsc::Sender<Result<SubscriptionMessage>>,
+) {
+    let mut subscriptions = Subscriptions::default();
+    loop {
+        let connected = tokio::select! {
+            command = commands.recv() => match command {
+                Some(command) => send_all(&mut socket, subscriptions.apply(command)).await,
+                // The subscriber was dropped
+                None => return,
+            },
+            message = socket.next() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    if let Some(message) = subscriptions.parse(&text) {
+                        // Waits while the buffer is full
+                        if messages.send(message).await.is_err() {
+                            return;
+                        }
+                    }
+                    true
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => false,
+                Some(Ok(_)) => true,
+            },
+        };
+
+        if !connected {
+            socket = match reconnect(&url, &headers, &subscriptions, &messages).await {
+                Some(socket) => socket,
+                None => return,
+            };
+        }
+    }
+}
+
+async fn send_all(socket: &mut Socket, frames: Vec<Message>) -> bool {
+    for frame in frames {
+        if socket.send(frame).await.is_err() {
+            return false;
+        }
+    }
+    true
+}
+
+/// Reconnects with an increasing delay and restores the subscriptions. Returns `None` once the subscriber is dropped.
+async fn reconnect(
+    url: &Url,
+    headers: &HeaderMap,
+    subscriptions: &Subscriptions,
+    messages: &mpsc::Sender<Result<SubscriptionMessage>>,
+) -> Option<Socket> {
+    let mut delay = RECONNECT_DELAY;
+    loop {
+        tokio::time::sleep(delay).await;
+        if messages.is_closed() {
+            return None;
+        }
+        let socket = match request(url, headers) {
+            Ok(request) => connect_async(request).await.map_err(Error::from),
+            Err(e) => Err(e),
+        };
+        match socket {
+            Ok((mut socket, _)) => {
+                if send_all(&mut socket, subscriptions.frames()).await {
+                    return Some(socket);
+                }
+            }
+            Err(e) => log::warn!("reconnecting to {url} failed: {e}"),
+        }
+        delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use futures::{SinkExt, StreamExt};
+    use iota_sdk::client::node_manager::node::NodeAuth;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::{
+        accept_async, accept_hdr_async,
+        tungstenite::{
+            handshake::server::{Request, Response},
+            http::header::AUTHORIZATION,
+            Message,
+        },
+        WebSocketStream,
+    };
+    use url::Url;
+
+    use crate::{Subscriber, SubscriptionMessage, Topic};
+
+    const CHAIN: &str = "rms1ppp00k5mmd2m8my8ukkp58nd3rskw6rx8l09aj35984k74uuc5u2cywn3ex";
+
+    fn new_block(chain_id: &str, block_index: u32) -> Message {
+        Message::Text(
+            serde_json::json!({
+                "kind": "new_block",
+                "issuer": "",
+                "requestID": "",
+                "chainID": chain_id,
+                "payload": {
+                    "blockIndex": block_index,
+                    "timestamp": "2024-01-01T00:00:00Z",
+                    "totalRequests": 1,
+                    "numSuccessfulRequests": 1,
+                    "numOffLedgerRequests": 0,
+                    "previousAliasOutput": "",
+                    "gasBurned": "100",
+                    "gasFeeCharged": "1",
+                },
+            })
+            .to_string(),
+        )
+    }
+
+    /// Reads subscribe commands until the given topics are subscribed.
+    async fn expect_subscriptions(socket: &mut WebSocketStream<tokio::net::TcpStream>, topics: &[&str]) {
+        let mut subscribed = HashSet::new();
+        while subscribed.len() < topics.len() {
+            let text = socket.next().await.unwrap().unwrap().into_text().unwrap();
+            let command: serde_json::Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(command["command"], "subscribe");
+            subscribed.insert(command["topic"].as_str().unwrap().to_string());
+        }
+        assert_eq!(subscribed, topics.iter().map(|t| t.to_string()).collect());
+    }
+
+    async fn listen() -> (TcpListener, Url) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+        (listener, url)
+    }
+
+    #[tokio::test]
+    async fn receives_subscribed_chain() {
+        let (listener, url) = listen().await;
+        let server = tokio::spawn(async move {
+            let mut socket = accept_async(listener.accept().await.unwrap().0).await.unwrap();
+            expect_subscriptions(&mut socket, &["new_block", CHAIN]).await;
+            socket.send(new_block("other", 1)).await.unwrap();
+            socket.send(new_block(CHAIN, 2)).await.unwrap();
+            socket
+        });
+
+        let mut subscriber = Subscriber::connect(url, None).await.unwrap();
+        subscriber.subscribe(CHAIN, &[Topic::NewBlock]);
+        match subscriber.next().await.unwrap().unwrap() {
+            SubscriptionMessage::NewBlock { chain_id, block } => {
+                assert_eq!(chain_id, CHAIN);
+                assert_eq!(block.block_index, 2);
+            }
+            message => panic!("unexpected message {message:?}"),
+        }
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn restores_subscriptions() {
+        let (listener, url) = listen().await;
+        let server = tokio::spawn(async move {
+            let mut socket = accept_async(listener.accept().await.unwrap().0).await.unwrap();
+            expect_subscriptions(&mut socket, &["new_block", CHAIN]).await;
+            socket.close(None).await.unwrap();
+
+            let mut socket = accept_async(listener.accept().await.unwrap().0).await.unwrap();
+            expect_subscriptions(&mut socket, &["new_block", CHAIN]).await;
+            socket.send(new_block(CHAIN, 3)).await.unwrap();
+            socket
+        });
+
+        let mut subscriber = Subscriber::connect(url, None).await.unwrap();
+        subscriber.subscribe(CHAIN, &[Topic::NewBlock]);
+        let message = subscriber.next().await.unwrap().unwrap();
+        assert_eq!(message.topic(), Topic::NewBlock);
+        assert_eq!(message.chain_id(), CHAIN);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn sends_auth() {
+        let (listener, url) = listen().await;
+        let server = tokio::spawn(async move {
+            let mut authorization = Vec::new();
+            for _ in 0..2 {
+                let stream = listener.accept().await.unwrap().0;
+                accept_hdr_async(stream, |request: &Request, response: Response| {
+                    authorization.push(request.headers()[AUTHORIZATION].to_str().unwrap().to_string());
+                    Ok(response)
+                })
+                .await
+                .unwrap();
+            }
+            authorization
+        });
+
+        let basic_auth = NodeAuth {
+            jwt: None,
+            basic_auth_name_pwd: Some(("user".to_string(), "password".to_string())),
+        };
+        let _subscriber = Subscriber::connect(url.clone(), Some(basic_auth.clone()))
+            .await
+            .unwrap();
+        let jwt = NodeAuth {
+            jwt: Some("token".to_string()),
+            ..basic_auth
+        };
+        let _subscriber = Subscriber::connect(url, Some(jwt)).await.unwrap();
+
+        assert_eq!(
+            server.await.unwrap(),
+            vec!["Basic dXNlcjpwYXNzd29yZA==", "Bearer token"]
+        );
+    }
+}
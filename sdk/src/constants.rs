@@ -16,6 +16,8 @@ pub const TESTNET_CHAIN_ADDRESS: &'static str = "rms1ppp00k5mmd2m8my8ukkp58nd3rs
 // The base chain address from the mainnet to which metadata tx should be send
 pub const MAINNET_CHAIN_ADDRESS: &'static str = "smr1prxvwqvwf7nru5q5xvh5thwg54zsm2y4wfnk6yk56hj3exxkg92mx20wl3s";
 
+/// The minimum gas fee of the default fee policy. Use [`ChainInfo::min_gas_fee`](crate::ChainInfo::min_gas_fee) to get
+/// the fee of a specific chain.
 pub const MIN_GAS_FEE: u64 = 100; // 0.0001 smr
 
 // use crate::RequestMetadata;
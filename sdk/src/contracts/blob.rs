@@ -0,0 +1,98 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use iota_sdk::types::block::output::AliasId;
+use serde::{Deserialize, Serialize};
+
+use crate::{ContractIdentity, OffLedgerRequest, RequestMetadata, BLOB};
+
+/// The entry point of the blob contract that stores a blob.
+pub const STORE_BLOB: &str = "storeBlob";
+
+/// The field holding the binary of a program.
+pub const BLOB_FIELD_PROGRAM_BINARY: &str = "p";
+/// The field holding the VM type of a program, for example `wasmtime`.
+pub const BLOB_FIELD_VM_TYPE: &str = "v";
+/// The field holding the description of a program.
+pub const BLOB_FIELD_PROGRAM_DESCRIPTION: &str = "d";
+
+/// A set of named fields stored as one blob by the blob contract.
+/// The hash of a blob containing a program is the program hash used to deploy it with the root contract.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Blob {
+    pub fields: HashMap<String, Vec<u8>>,
+}
+
+impl Blob {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a blob for a program with the given VM type and description.
+    pub fn program(vm_type: &str, binary: Vec<u8>, description: &str) -> Self {
+        Self::new()
+            .with_field(BLOB_FIELD_VM_TYPE, vm_type.as_bytes().to_vec())
+            .with_field(BLOB_FIELD_PROGRAM_BINARY, binary)
+            .with_field(BLOB_FIELD_PROGRAM_DESCRIPTION, description.as_bytes().to_vec())
+    }
+
+    pub fn with_field(mut self, name: impl Into<String>, value: Vec<u8>) -> Self {
+        self.fields.insert(name.into(), value);
+        self
+    }
+
+    /// Computes the hash the chain identifies the blob with: the Blake2b256 hash of each value followed by its name,
+    /// ordered by name.
+    pub fn hash(&self) -> [u8; 32] {
+        let mut fields = self.fields.iter().collect::<Vec<_>>();
+        fields.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut hasher = Blake2b256::new();
+        for (name, value) in fields {
+            hasher.update(value);
+            hasher.update(name.as_bytes());
+        }
+        hasher.finalize().into()
+    }
+
+    /// Builds the metadata of an on-ledger request that stores the blob.
+    pub fn store_request_metadata(&self, sender_contract: ContractIdentity, gas_budget: u64) -> RequestMetadata {
+        let mut metadata = RequestMetadata::new(sender_contract, BLOB.to_string(), STORE_BLOB.to_string(), gas_budget);
+        metadata.params = self.fields.clone();
+        metadata
+    }
+
+    /// Builds an off-ledger request that stores the blob. It has to be signed before it can be submitted.
+    pub fn store_off_ledger_request(&self, chain_id: AliasId, nonce: u64, gas_budget: u64) -> OffLedgerRequest {
+        let mut request = OffLedgerRequest::new(chain_id, BLOB.to_string(), STORE_BLOB.to_string(), nonce, gas_budget);
+        request.params = self.fields.clone();
+        request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Blob, ContractIdentity};
+
+    #[tokio::test]
+    async fn hash() {
+        let blob = Blob::new()
+            .with_field("v", b"wasmtime".to_vec())
+            .with_field("p", b"\0asm".to_vec());
+        assert_eq!(
+            prefix_hex::encode(blob.hash()),
+            "0xaa2a1a26c308b5bdd029e0a1ac52b85b6bfe5949db4f9dcf425d178586d159ba"
+        );
+        assert_ne!(blob.hash(), blob.clone().with_field("d", Vec::new()).hash());
+    }
+
+    #[tokio::test]
+    async fn store_request() {
+        let blob = Blob::program("wasmtime", b"\0asm".to_vec(), "test");
+        let metadata = blob.store_request_metadata(ContractIdentity::Null, 10000);
+        assert_eq!(metadata.params, blob.fields);
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use iota_sdk::packable::{
+    error::UnpackError,
+    unpacker::{SliceUnpacker, Unpacker},
+    Packable, PackableExt,
+};
+
+use crate::{Error, Event, Result, U64Special};
+
+pub const EVENT_FOUNDRY_CREATED: &str = "coreaccounts.foundryCreated";
+pub const EVENT_FOUNDRY_DESTROYED: &str = "coreaccounts.foundryDestroyed";
+pub const EVENT_FOUNDRY_MODIFIED: &str = "coreaccounts.foundryModified";
+pub const EVENT_DEPLOY: &str = "coreroot.deploy";
+pub const EVENT_GRANT_DEPLOY_PERMISSION: &str = "coreroot.grant";
+pub const EVENT_REVOKE_DEPLOY_PERMISSION: &str = "coreroot.revoke";
+
+/// Events emitted by the core contracts.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CoreEvent {
+    /// A foundry with the given serial number was created.
+    FoundryCreated { serial_number: u32 },
+    /// The foundry with the given serial number was destroyed.
+    FoundryDestroyed { serial_number: u32 },
+    /// The supply of the foundry with the given serial number changed.
+    FoundryModified { serial_number: u32 },
+    /// A contract was deployed.
+    Deploy {
+        program_hash: [u8; 32],
+        name: String,
+        description: String,
+    },
+    /// Deploy permission was granted to the agent ID, given as its serialized bytes.
+    GrantDeployPermission { deployer: Vec<u8> },
+    /// Deploy permission was revoked from the agent ID, given as its serialized bytes.
+    RevokeDeployPermission { deployer: Vec<u8> },
+}
+
+/// Decodes the payload of an event with a known topic.
+pub type EventDecoder<T> = fn(&[u8]) -> Result<T>;
+
+/// Maps event topics to decoders.
+/// The default registry knows the events of the core contracts, other contracts can register their own.
+pub struct EventRegistry<T> {
+    decoders: HashMap<String, EventDecoder<T>>,
+}
+
+impl<T> EventRegistry<T> {
+    /// Creates a registry without any decoders.
+    pub fn new() -> Self {
+        Self {
+            decoders: HashMap::new(),
+        }
+    }
+
+    /// Registers a decoder for a topic, replacing any previous decoder for it.
+    pub fn register(&mut self, topic: impl Into<String>, decoder: EventDecoder<T>) -> &mut Self {
+        self.decoders.insert(topic.into(), decoder);
+        self
+    }
+
+    /// Decodes the payload of an event. Returns `None` if no decoder is registered for its topic.
+    pub fn decode(&self, event: &Event) -> Option<Result<T>> {
+        self.decoders.get(&event.topic).map(|decoder| decoder(&event.payload))
+    }
+}
+
+impl Default for EventRegistry<CoreEvent> {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register(EVENT_FOUNDRY_CREATED, |payload| {
+                Ok(CoreEvent::FoundryCreated {
+                    serial_number: u32::unpack_unverified(payload)?,
+                })
+            })
+            .register(EVENT_FOUNDRY_DESTROYED, |payload| {
+                Ok(CoreEvent::FoundryDestroyed {
+                    serial_number: u32::unpack_unverified(payload)?,
+                })
+            })
+            .register(EVENT_FOUNDRY_MODIFIED, |payload| {
+                Ok(CoreEvent::FoundryModified {
+                    serial_number: u32::unpack_unverified(payload)?,
+                })
+            })
+            .register(EVENT_DEPLOY, |payload| {
+                let mut unpacker = SliceUnpacker::new(payload);
+                Ok(CoreEvent::Deploy {
+                    program_hash: <[u8; 32]>::unpack::<_, true>(&mut unpacker, &())?,
+                    name: unpack_string(&mut unpacker)?,
+                    description: unpack_string(&mut unpacker)?,
+                })
+            })
+            .register(EVENT_GRANT_DEPLOY_PERMISSION, |payload| {
+                Ok(CoreEvent::GrantDeployPermission {
+                    deployer: payload.to_vec(),
+                })
+            })
+            .register(EVENT_REVOKE_DEPLOY_PERMISSION, |payload| {
+                Ok(CoreEvent::RevokeDeployPermission {
+                    deployer: payload.to_vec(),
+                })
+            });
+        registry
+    }
+}
+
+/// Unpacks a string prefixed with its size64 encoded length.
+fn unpack_string(unpacker: &mut SliceUnpacker<'_>) -> Result<String> {
+    let len = *U64Special::unpack::<_, true>(unpacker, &())?;
+    let mut bytes = vec![0u8; len as usize];
+    unpacker
+        .unpack_bytes(&mut bytes)
+        .map_err(UnpackError::<Error, _>::Unpacker)?;
+    Ok(String::from_utf8(bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hname, CoreEvent, Event, EventRegistry, ACCOUNTS, EVENT_DEPLOY, EVENT_FOUNDRY_CREATED};
+
+    fn event(topic: &str, payload: Vec<u8>) -> Event {
+        Event {
+            contract_hname: hname(ACCOUNTS),
+            topic: topic.to_string(),
+            timestamp: 0,
+            payload,
+        }
+    }
+
+    #[tokio::test]
+    async fn decode_core_events() {
+        let registry = EventRegistry::default();
+        assert_eq!(
+            registry
+                .decode(&event(EVENT_FOUNDRY_CREATED, vec![5, 0, 0, 0]))
+                .unwrap()
+                .unwrap(),
+            CoreEvent::FoundryCreated { serial_number: 5 }
+        );
+
+        let mut payload = vec![7; 32];
+        payload.extend([3, b'f', b'o', b'o', 0]);
+        assert_eq!(
+            registry.decode(&event(EVENT_DEPLOY, payload)).unwrap().unwrap(),
+            CoreEvent::Deploy {
+                program_hash: [7; 32],
+                name: "foo".to_string(),
+                description: String::new(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_topic() {
+        let registry = EventRegistry::default();
+        assert!(registry.decode(&event("mycontract.event", vec![])).is_none());
+    }
+}
@@ -1,17 +1,28 @@
 // Copyright 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod blob;
 mod core;
+mod events;
+mod state;
 
 pub use core::*;
 
-use iota_sdk::packable::{
-    error::{UnpackError, UnpackErrorExt},
-    packer::Packer,
-    unpacker::Unpacker,
-    Packable,
+pub use blob::*;
+pub use events::*;
+use iota_sdk::{
+    packable::{
+        error::{UnpackError, UnpackErrorExt},
+        packer::Packer,
+        unpacker::Unpacker,
+        Packable,
+    },
+    types::block::address::Bech32Address,
 };
 use serde::{Deserialize, Serialize};
+pub use state::*;
+
+use crate::{ContractIdentity, EvmAddress};
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct AgentId {
@@ -58,3 +69,44 @@ impl Packable for AgentId {
         }
     }
 }
+
+/// Types that identify an L2 account and can be used as agent ID in the paths of the Wasp API.
+pub trait ToAgentIdString {
+    /// Returns the agent ID the way Wasp parses it. The chain is only needed for contract agent IDs.
+    fn to_agent_id_string(&self, chain: &str) -> String;
+}
+
+impl ToAgentIdString for str {
+    fn to_agent_id_string(&self, _chain: &str) -> String {
+        self.to_string()
+    }
+}
+
+impl ToAgentIdString for Bech32Address {
+    fn to_agent_id_string(&self, _chain: &str) -> String {
+        self.to_string()
+    }
+}
+
+impl ToAgentIdString for EvmAddress {
+    fn to_agent_id_string(&self, _chain: &str) -> String {
+        prefix_hex::encode(self.as_ref())
+    }
+}
+
+impl ToAgentIdString for AgentId {
+    fn to_agent_id_string(&self, _chain: &str) -> String {
+        format!("0x{}", self.address)
+    }
+}
+
+impl ToAgentIdString for ContractIdentity {
+    fn to_agent_id_string(&self, chain: &str) -> String {
+        match self {
+            ContractIdentity::Null => "-".to_string(),
+            ContractIdentity::ISC(hname) => format!("{hname:08x}@{chain}"),
+            ContractIdentity::EVM(address) => address.to_agent_id_string(chain),
+            ContractIdentity::ETH(agent) => agent.to_agent_id_string(chain),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::packable::{Packable, PackableExt};
+
+use crate::{Error, Result};
+
+/// Separates the name of a map from the keys of its elements in the state of a contract.
+const MAP_ELEMENT_SEPARATOR: u8 = b'.';
+
+/// Builds the key of a field in the chain state: the partition of the contract, which is its hname in little endian,
+/// followed by the key of the field within the contract.
+pub fn state_key(contract_hname: u32, field: impl AsRef<[u8]>) -> Vec<u8> {
+    let mut key = contract_hname.to_le_bytes().to_vec();
+    key.extend_from_slice(field.as_ref());
+    key
+}
+
+/// Builds the state key of an element of a map stored by a contract.
+pub fn map_element_state_key(
+    contract_hname: u32,
+    map_name: impl AsRef<[u8]>,
+    element_key: impl AsRef<[u8]>,
+) -> Vec<u8> {
+    let mut field = map_name.as_ref().to_vec();
+    field.push(MAP_ELEMENT_SEPARATOR);
+    field.extend_from_slice(element_key.as_ref());
+    state_key(contract_hname, field)
+}
+
+/// Decodes a value of the chain state, for example a [`U64Special`](crate::U64Special).
+pub fn decode_state_value<T>(bytes: impl AsRef<[u8]>) -> Result<T>
+where
+    T: Packable,
+    T::UnpackError: Into<Error>,
+{
+    Ok(T::unpack_unverified(bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode_state_value, hname, map_element_state_key, state_key, U64Special, ACCOUNTS};
+
+    #[tokio::test]
+    async fn keys() {
+        assert_eq!(state_key(hname(ACCOUNTS), "n"), vec![0x02, 0x5e, 0x4b, 0x3c, b'n']);
+        assert_eq!(
+            map_element_state_key(0x01020304, "m", [0xff]),
+            vec![0x04, 0x03, 0x02, 0x01, b'm', b'.', 0xff]
+        );
+    }
+
+    #[tokio::test]
+    async fn decode() {
+        assert_eq!(*decode_state_value::<U64Special>([0x80, 0x01]).unwrap(), 128);
+        assert_eq!(decode_state_value::<u32>([1, 0, 0, 0]).unwrap(), 1);
+        assert!(decode_state_value::<u32>([1]).is_err());
+    }
+}
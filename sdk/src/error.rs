@@ -5,7 +5,13 @@
 
 use std::{convert::Infallible, fmt::Debug, string::FromUtf8Error};
 
-use iota_sdk::{client::Error as ClientError, types::block::Error as SdkBlockError, wallet::Error as WalletError};
+use instant::Duration;
+use iota_sdk::{
+    client::Error as ClientError,
+    packable::error::{UnexpectedEOF, UnpackError},
+    types::block::{output::OutputId, Error as SdkBlockError},
+    wallet::Error as WalletError,
+};
 use serde::{
     ser::{SerializeMap, Serializer},
     Serialize,
@@ -30,11 +36,48 @@ pub enum Error {
     #[error("{0}")]
     ClientError(#[from] ClientError),
 
+    #[error("Could not connect to the node: {0}")]
+    Connect(String),
+    /// A request that was sent more than once failed, `source` is the error of the last attempt. Returned whether or
+    /// not that error could have been retried.
+    #[error("Request failed after {attempts} attempts: {source}")]
+    RetriesExhausted { attempts: u32, source: Box<Error> },
+    #[error("Request {request_id} was not processed within {timeout:?}")]
+    RequestTimeout { request_id: OutputId, timeout: Duration },
+
+    #[error("Expected a {expected} output, found kind {found}")]
+    UnexpectedOutputKind { expected: &'static str, found: u8 },
+
     #[error("Invalid contract identity kind found: {0}")]
     InvalidContractIdentityKind(u8),
+    #[error("Invalid header: {0}")]
+    InvalidHeader(String),
+    #[error("Invalid agent ID: {0}")]
+    InvalidAgentId(String),
+    #[error("Invalid VM error code: {0}")]
+    InvalidVmErrorCode(String),
+    #[error("EVM JSON-RPC error {code}: {message}")]
+    EvmRpc { code: i64, message: String },
 
     #[error("{0}")]
     Utf8(#[from] FromUtf8Error),
+    #[error("{0}")]
+    Hex(#[from] prefix_hex::Error),
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+    #[cfg(not(target_family = "wasm"))]
+    #[error("{0}")]
+    WebSocket(#[from] tokio_tungstenite::tungstenite::Error),
+}
+
+impl Error {
+    /// The error of the last attempt if the request was retried, this error otherwise.
+    pub fn last_attempt(&self) -> &Error {
+        match self {
+            Self::RetriesExhausted { source, .. } => source.last_attempt(),
+            error => error,
+        }
+    }
 }
 
 impl From<Infallible> for Error {
@@ -43,6 +86,18 @@ impl From<Infallible> for Error {
     }
 }
 
+impl<E: Into<Error>> From<UnpackError<E, UnexpectedEOF>> for Error {
+    fn from(error: UnpackError<E, UnexpectedEOF>) -> Self {
+        match error {
+            UnpackError::Packable(e) => e.into(),
+            UnpackError::Unpacker(_) => Self::IO {
+                expected: std::io::ErrorKind::UnexpectedEof,
+                message: "not enough bytes to unpack",
+            },
+        }
+    }
+}
+
 // Serialize type with Display error
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -0,0 +1,133 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::str::FromStr;
+
+use iota_sdk::types::block::address::Bech32Address;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Error, EvmAddress, ToAgentIdString};
+
+/// The owner of an L2 account, parsed from the agent ID string format of Wasp.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgentIdentity {
+    /// The agent ID nobody owns, `-`.
+    Nil,
+    /// An L1 address, `<bech32>`.
+    Address(Bech32Address),
+    /// A contract on a chain, `<hname>@<chain>`.
+    Contract { hname: u32, chain: Bech32Address },
+    /// An EVM account, `0x<address>@<chain>`. Wasp also accepts it without the chain.
+    Ethereum {
+        address: EvmAddress,
+        chain: Option<Bech32Address>,
+    },
+}
+
+impl FromStr for AgentIdentity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "-" {
+            return Ok(Self::Nil);
+        }
+        let (id, chain) = match s.split_once('@') {
+            Some((id, chain)) => (id, Some(Bech32Address::try_from_str(chain)?)),
+            None => (s, None),
+        };
+        if id.starts_with("0x") {
+            return Ok(Self::Ethereum {
+                address: EvmAddress::from(prefix_hex::decode::<[u8; 20]>(id)?),
+                chain,
+            });
+        }
+        if let Some(chain) = chain {
+            let hname = u32::from_str_radix(id, 16).map_err(|_| Error::InvalidAgentId(s.to_string()))?;
+            return Ok(Self::Contract { hname, chain });
+        }
+        Bech32Address::try_from_str(s)
+            .map(Self::Address)
+            .map_err(|_| Error::InvalidAgentId(s.to_string()))
+    }
+}
+
+impl core::fmt::Display for AgentIdentity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Nil => write!(f, "-"),
+            Self::Address(address) => write!(f, "{address}"),
+            Self::Contract { hname, chain } => write!(f, "{hname:08x}@{chain}"),
+            Self::Ethereum { address, chain } => {
+                write!(f, "{}", prefix_hex::encode(address.as_ref()))?;
+                match chain {
+                    Some(chain) => write!(f, "@{chain}"),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl ToAgentIdString for AgentIdentity {
+    fn to_agent_id_string(&self, _chain: &str) -> String {
+        self.to_string()
+    }
+}
+
+impl Serialize for AgentIdentity {
+    fn serialize<S: Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AgentIdentity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_sdk::types::block::address::Bech32Address;
+
+    use crate::{hname, AgentIdentity, EvmAddress, ACCOUNTS, TESTNET_CHAIN_ADDRESS};
+
+    #[tokio::test]
+    async fn parse() {
+        let chain = Bech32Address::try_from_str(TESTNET_CHAIN_ADDRESS).unwrap();
+        let agents = [
+            ("-", AgentIdentity::Nil),
+            (TESTNET_CHAIN_ADDRESS, AgentIdentity::Address(chain)),
+            (
+                "3c4b5e02@rms1ppp00k5mmd2m8my8ukkp58nd3rskw6rx8l09aj35984k74uuc5u2cywn3ex",
+                AgentIdentity::Contract {
+                    hname: hname(ACCOUNTS),
+                    chain,
+                },
+            ),
+            (
+                "0xabababababababababababababababababababab",
+                AgentIdentity::Ethereum {
+                    address: EvmAddress::from([0xab; 20]),
+                    chain: None,
+                },
+            ),
+            // The format Wasp returns
+            (
+                "0xabababababababababababababababababababab@\
+                 rms1ppp00k5mmd2m8my8ukkp58nd3rskw6rx8l09aj35984k74uuc5u2cywn3ex",
+                AgentIdentity::Ethereum {
+                    address: EvmAddress::from([0xab; 20]),
+                    chain: Some(chain),
+                },
+            ),
+        ];
+
+        for (s, agent) in agents {
+            assert_eq!(s.parse::<AgentIdentity>().unwrap(), agent);
+            assert_eq!(agent.to_string(), s);
+        }
+        assert!("not an agent".parse::<AgentIdentity>().is_err());
+    }
+}
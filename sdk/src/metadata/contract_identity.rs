@@ -116,7 +116,7 @@ impl<'de> Deserialize<'de> for ContractIdentity {
 mod tests {
     use iota_sdk::packable::PackableExt;
 
-    use crate::{hname, ContractIdentity, ACCOUNTS};
+    use crate::{hname, ContractIdentity, EvmAddress, ToAgentIdString, ACCOUNTS};
 
     const ISC: &str = "01025e4b3c";
 
@@ -126,4 +126,18 @@ mod tests {
         matches!(evm, ContractIdentity::ISC(1011572226));
         assert_eq!(ContractIdentity::ISC(hname(ACCOUNTS)), evm);
     }
+
+    #[tokio::test]
+    async fn agent_id_string() {
+        let chain = "rms1ppp00k5mmd2m8my8ukkp58nd3rskw6rx8l09aj35984k74uuc5u2cywn3ex";
+        assert_eq!(
+            ContractIdentity::ISC(hname(ACCOUNTS)).to_agent_id_string(chain),
+            format!("3c4b5e02@{chain}")
+        );
+        assert_eq!(
+            ContractIdentity::EVM(EvmAddress::from([0xab; 20])).to_agent_id_string(chain),
+            format!("0x{}", "ab".repeat(20))
+        );
+        assert_eq!(ContractIdentity::Null.to_agent_id_string(chain), "-");
+    }
 }
@@ -1,10 +1,14 @@
 // Copyright 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod agent_identity;
 mod assets;
 mod contract_identity;
+mod off_ledger_request;
 mod request_metadata;
 
+pub use agent_identity::*;
 pub use assets::*;
 pub use contract_identity::*;
+pub use off_ledger_request::*;
 pub use request_metadata::*;
@@ -0,0 +1,304 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use iota_sdk::{
+    client::secret::SecretManage,
+    crypto::{keys::bip44::Bip44, signatures::ed25519::SecretKey},
+    packable::{
+        error::{UnpackError, UnpackErrorExt},
+        packer::Packer,
+        unpacker::Unpacker,
+        Packable, PackableExt,
+    },
+    types::block::{
+        output::{AliasId, OutputId},
+        payload::transaction::TransactionId,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{hname, Assets, U64Special};
+
+/// The kind byte Wasp uses for off-ledger ISC requests.
+pub const OFF_LEDGER_REQUEST_KIND: u8 = 1;
+/// The longest param key, param value or signature that is unpacked, so a crafted length can't exhaust the memory.
+const MAX_FIELD_LENGTH: u64 = 1024 * 1024;
+
+/// A request that is sent directly to a Wasp node instead of through an L1 transaction.
+/// It has to be signed before it can be submitted.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OffLedgerRequest {
+    chain_id: AliasId,
+    target_contract: u32,
+    target_entry_point: u32,
+    pub params: HashMap<String, Vec<u8>>,
+    nonce: U64Special,
+    gas_budget: U64Special,
+    pub allowance: Assets,
+    #[serde(default, with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+    public_key: [u8; 32],
+    #[serde(default, with = "iota_sdk::utils::serde::prefix_hex_bytes")]
+    signature: Vec<u8>,
+}
+
+impl OffLedgerRequest {
+    pub fn new(
+        chain_id: AliasId,
+        target_contract: impl Into<Option<String>>,
+        target_entry_point: impl Into<Option<String>>,
+        nonce: u64,
+        gas_budget: u64,
+    ) -> Self {
+        OffLedgerRequest {
+            chain_id,
+            target_contract: target_contract.into().map_or(0, |tc| hname(&tc)),
+            target_entry_point: target_entry_point.into().map_or(0, |tep| hname(&tep)),
+            params: Default::default(),
+            nonce: nonce.into(),
+            gas_budget: gas_budget.into(),
+            allowance: Assets::default(),
+            public_key: [0; 32],
+            signature: Vec::new(),
+        }
+    }
+
+    pub fn chain_id(&self) -> &AliasId {
+        &self.chain_id
+    }
+
+    pub fn nonce(&self) -> u64 {
+        *self.nonce
+    }
+
+    pub fn gas_budget(&self) -> u64 {
+        *self.gas_budget
+    }
+
+    pub fn public_key(&self) -> &[u8; 32] {
+        &self.public_key
+    }
+
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    pub fn is_signed(&self) -> bool {
+        !self.signature.is_empty()
+    }
+
+    /// The bytes covered by the signature: the request kind followed by everything but the signature.
+    pub fn essence_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        // Packing into a Vec can't fail
+        self.pack_essence(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// Signs the request with an ed25519 key.
+    pub fn sign(&mut self, secret_key: &SecretKey) {
+        let signature = secret_key.sign(&self.essence_bytes());
+        self.public_key = secret_key.public_key().to_bytes();
+        self.signature = signature.to_bytes().to_vec();
+    }
+
+    /// Signs the request with the ed25519 key of the given [`Bip44`] chain of a secret manager.
+    pub async fn sign_with_secret_manager<S: SecretManage>(
+        &mut self,
+        secret_manager: &S,
+        chain: Bip44,
+    ) -> crate::Result<()>
+    where
+        crate::Error: From<S::Error>,
+    {
+        let signature = secret_manager.sign_ed25519(&self.essence_bytes(), chain).await?;
+        self.public_key = signature.public_key_bytes().to_bytes();
+        self.signature = signature.signature().to_bytes().to_vec();
+        Ok(())
+    }
+
+    /// The request ID Wasp assigns to this request: the hash of the request bytes as transaction ID, with index 0.
+    pub fn id(&self) -> crate::Result<OutputId> {
+        let hash: [u8; 32] = Blake2b256::digest(self.pack_to_vec()).into();
+        Ok(OutputId::new(TransactionId::new(hash), 0)?)
+    }
+
+    fn pack_essence<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        OFF_LEDGER_REQUEST_KIND.pack(packer)?;
+        self.chain_id.pack(packer)?;
+        self.target_contract.to_le_bytes().pack(packer)?;
+        self.target_entry_point.to_le_bytes().pack(packer)?;
+
+        // Wasp serializes dicts with sorted keys
+        let mut params = self.params.iter().collect::<Vec<_>>();
+        params.sort_by(|a, b| a.0.cmp(b.0));
+        U64Special::pack(&(params.len() as u64).into(), packer)?;
+        for (key, value) in params {
+            U64Special::pack(&(key.as_bytes().len() as u64).into(), packer)?;
+            packer.pack_bytes(key)?;
+            U64Special::pack(&(value.len() as u64).into(), packer)?;
+            packer.pack_bytes(value)?;
+        }
+
+        self.nonce.pack(packer)?;
+        // Wasp encodes the gas budget plus one, wrapping around like Go
+        Into::<U64Special>::into(self.gas_budget.wrapping_add(1)).pack(packer)?;
+        self.allowance.pack(packer)
+    }
+}
+
+impl Packable for OffLedgerRequest {
+    type UnpackError = crate::Error;
+
+    type UnpackVisitor = ();
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.pack_essence(packer)?;
+        packer.pack_bytes(self.public_key)?;
+        U64Special::pack(&(self.signature.len() as u64).into(), packer)?;
+        packer.pack_bytes(&self.signature)
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let kind = u8::unpack::<_, VERIFY>(unpacker, visitor).coerce()?;
+        if kind != OFF_LEDGER_REQUEST_KIND {
+            return Err(UnpackError::Packable(crate::Error::IO {
+                expected: std::io::ErrorKind::InvalidData,
+                message: "invalid off-ledger request kind",
+            }));
+        }
+        let chain_id = AliasId::unpack::<_, VERIFY>(unpacker, visitor).coerce()?;
+        let target_contract = u32::unpack::<_, VERIFY>(unpacker, visitor).coerce()?.to_le();
+        let target_entry_point = u32::unpack::<_, VERIFY>(unpacker, visitor).coerce()?.to_le();
+
+        let mut params = HashMap::new();
+        let params_len = *U64Special::unpack::<_, VERIFY>(unpacker, visitor)?;
+        for _ in 0..params_len {
+            let key_len = unpack_len::<_, VERIFY>(unpacker, visitor)?;
+            let mut key = vec![0u8; key_len];
+            unpacker.unpack_bytes(&mut key)?;
+            let entry_len = unpack_len::<_, VERIFY>(unpacker, visitor)?;
+            let mut entry = vec![0u8; entry_len];
+            unpacker.unpack_bytes(&mut entry)?;
+            params.insert(
+                String::from_utf8(key).map_err(|e| UnpackError::Packable(e.into()))?,
+                entry,
+            );
+        }
+
+        let nonce = U64Special::unpack::<_, VERIFY>(unpacker, visitor)?;
+        let gas_budget = U64Special::unpack::<_, VERIFY>(unpacker, visitor)?
+            .wrapping_sub(1)
+            .into();
+        let allowance = Assets::unpack::<U, VERIFY>(unpacker, visitor)?;
+
+        let mut public_key = [0u8; 32];
+        unpacker.unpack_bytes(&mut public_key)?;
+        let signature_len = unpack_len::<_, VERIFY>(unpacker, visitor)?;
+        let mut signature = vec![0u8; signature_len];
+        unpacker.unpack_bytes(&mut signature)?;
+
+        Ok(OffLedgerRequest {
+            chain_id,
+            target_contract,
+            target_entry_point,
+            params,
+            nonce,
+            gas_budget,
+            allowance,
+            public_key,
+            signature,
+        })
+    }
+}
+
+/// Unpacks the length of a field and checks it before anything is allocated for it.
+fn unpack_len<U: Unpacker, const VERIFY: bool>(
+    unpacker: &mut U,
+    visitor: &(),
+) -> Result<usize, UnpackError<crate::Error, U::Error>> {
+    let len = *U64Special::unpack::<_, VERIFY>(unpacker, visitor)?;
+    if len > MAX_FIELD_LENGTH {
+        return Err(UnpackError::Packable(crate::Error::IO {
+            expected: std::io::ErrorKind::InvalidData,
+            message: "off-ledger request field too long",
+        }));
+    }
+    let len = len as usize;
+    unpacker.ensure_bytes(len)?;
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_sdk::{
+        crypto::signatures::ed25519::{PublicKey, SecretKey, Signature},
+        packable::PackableExt,
+        types::block::output::AliasId,
+    };
+
+    use crate::{size64_encode, OffLedgerRequest, ACCOUNTS};
+
+    fn get_request() -> OffLedgerRequest {
+        let mut request = OffLedgerRequest::new(
+            AliasId::new([1; 32]),
+            ACCOUNTS.to_string(),
+            "withdraw".to_string(),
+            7,
+            10000,
+        );
+        request.params.insert("b".to_string(), vec![2]);
+        request.params.insert("a".to_string(), vec![1]);
+        request.allowance.set_base_tokens(1000);
+        request
+    }
+
+    #[tokio::test]
+    async fn pack_sorts_params() {
+        let essence = get_request().essence_bytes();
+        // kind + chain id + contract + entry point
+        let params = &essence[1 + 32 + 4 + 4..];
+        assert_eq!(&params[..7], &[2, 1, b'a', 1, 1, 1, b'b']);
+    }
+
+    #[tokio::test]
+    async fn sign_and_unpack() {
+        let secret_key = SecretKey::from_bytes(&[3; 32]);
+        let mut request = get_request();
+        assert!(!request.is_signed());
+        request.sign(&secret_key);
+        assert!(request.is_signed());
+
+        let public_key = PublicKey::try_from_bytes(*request.public_key()).unwrap();
+        let signature = Signature::from_bytes(request.signature().try_into().unwrap());
+        assert!(public_key.verify(&signature, &request.essence_bytes()));
+
+        let unpacked = OffLedgerRequest::unpack_unverified(request.pack_to_vec()).unwrap();
+        assert_eq!(request, unpacked);
+        assert_eq!(request.id().unwrap(), unpacked.id().unwrap());
+    }
+
+    #[tokio::test]
+    async fn gas_budget_wraps() {
+        let mut request = get_request();
+        request.gas_budget = u64::MAX.into();
+        // Packed as 0, which unpacks to u64::MAX again
+        let unpacked = OffLedgerRequest::unpack_unverified(request.pack_to_vec()).unwrap();
+        assert_eq!(unpacked.gas_budget(), u64::MAX);
+    }
+
+    #[tokio::test]
+    async fn rejects_long_fields() {
+        let mut bytes = get_request().pack_to_vec();
+        // The length of the empty signature
+        assert_eq!(bytes.pop(), Some(0));
+        bytes.extend(size64_encode(u64::MAX));
+        assert!(OffLedgerRequest::unpack_unverified(bytes).is_err());
+    }
+}
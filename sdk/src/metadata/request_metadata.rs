@@ -4,11 +4,21 @@
 use std::collections::HashMap;
 
 use crypto::hashes::{blake2b::Blake2b256, Digest};
-use iota_sdk::packable::{
-    error::{UnpackError, UnpackErrorExt},
-    packer::Packer,
-    unpacker::Unpacker,
-    Packable,
+use iota_sdk::{
+    packable::{
+        error::{UnpackError, UnpackErrorExt},
+        packer::Packer,
+        unpacker::Unpacker,
+        Packable, PackableExt,
+    },
+    types::block::{
+        address::{Address, Bech32Address},
+        output::{
+            feature::{MetadataFeature, SenderFeature},
+            unlock_condition::AddressUnlockCondition,
+            BasicOutputBuilder, Output,
+        },
+    },
 };
 use serde::{Deserialize, Serialize};
 
@@ -43,6 +53,16 @@ impl RequestMetadata {
             gas_budget: gas_budget.into(),
         }
     }
+
+    /// Builds the output that sends this request on-ledger from `sender` to a chain, carrying `amount` base tokens.
+    pub fn to_output(&self, chain: &Bech32Address, sender: &Address, amount: u64) -> crate::Result<Output> {
+        Ok(BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(*chain.inner()))
+            .add_feature(MetadataFeature::new(self.pack_to_vec())?)
+            .add_feature(SenderFeature::new(*sender))
+            .finish()?
+            .into())
+    }
 }
 
 impl Packable for RequestMetadata {
@@ -135,10 +155,13 @@ mod tests {
     use crypto::signatures::secp256k1_ecdsa::EvmAddress;
     use iota_sdk::{
         packable::PackableExt,
-        types::block::output::{NativeToken, TokenId},
+        types::block::{
+            address::{Address, Bech32Address, Ed25519Address},
+            output::{NativeToken, TokenId},
+        },
     };
 
-    use crate::{ethereum_agent_id, hname, ContractIdentity, RequestMetadata, ACCOUNTS};
+    use crate::{ethereum_agent_id, hname, ContractIdentity, RequestMetadata, ACCOUNTS, TESTNET_CHAIN_ADDRESS};
 
     const SER: &str = "00025e4b3ca1e3f423914e0101613503e14c3499349cb8d2fd771e09829883e4ecfae02e6b09c9b6a0fb3c7504b4e2f4e913cac59e0ba840039add645d5df83c294cc230400108e14c3499349cb8d2fd771e09829883e4ecfae02e6b09c9b6a0fb3c7504b4e2f401000000000132";
     const SER_ISC: &str = "01025e4b3c0000000000000000010000";
@@ -215,6 +238,23 @@ mod tests {
         assert_eq!("0x00025e4b3c410fcc9d914e008098d04f", enc);
     }
 
+    #[tokio::test]
+    async fn to_output() {
+        let chain = Bech32Address::try_from_str(TESTNET_CHAIN_ADDRESS).unwrap();
+        let sender = Address::from(Ed25519Address::new([1; 32]));
+        let metadata = get_metadata();
+
+        let output = metadata.to_output(&chain, &sender, 1000000).unwrap();
+        let output = output.as_basic();
+        assert_eq!(output.amount(), 1000000);
+        assert_eq!(output.address(), chain.inner());
+        assert_eq!(output.features().sender().unwrap().address(), &sender);
+        assert_eq!(
+            output.features().metadata().unwrap().data(),
+            hex::decode(SER).unwrap().as_slice()
+        );
+    }
+
     fn get_metadata() -> RequestMetadata {
         let mut metadata = RequestMetadata::new(
             ContractIdentity::Null,